@@ -51,6 +51,17 @@ export function activate(context: vscode.ExtensionContext) {
             return new vscode.Selection(anchor, active)
         })
     })
+    registerCommand('libsyntax-rust.ssr', async () => {
+        let rule = await vscode.window.showInputBox({
+            prompt: "Structural search and replace",
+            placeHolder: "$a.unwrap_or(Default::default()) ==>> $a.unwrap_or_default()",
+        })
+        if (rule == null) return
+        await client.sendRequest(lc.ExecuteCommandRequest.type, {
+            command: "ssr",
+            arguments: [rule],
+        })
+    })
 
     dispose(vscode.workspace.registerTextDocumentContentProvider(
         'libsyntax-rust',
@@ -1,16 +1,19 @@
 extern crate clap;
 #[macro_use]
 extern crate failure;
+extern crate serde_json;
+extern crate libsyntax2;
 extern crate libeditor;
 extern crate tools;
 
 use std::{
     fs, io::Read, path::Path,
-    time::Instant
+    time::{Duration, Instant},
 };
 use clap::{App, Arg, SubCommand};
 use tools::collect_tests;
-use libeditor::{File, syntax_tree, file_structure};
+use libsyntax2::{GreenInterner, utils::TreeMemory, algo::walk, ast::AstNode};
+use libeditor::{File, SsrRule, syntax_tree, file_structure, ssr};
 
 type Result<T> = ::std::result::Result<T, failure::Error>;
 
@@ -35,18 +38,48 @@ fn main() -> Result<()> {
         .subcommand(
             SubCommand::with_name("parse")
                 .arg(Arg::with_name("no-dump").long("--no-dump"))
+                .arg(
+                    Arg::with_name("format")
+                        .long("--format")
+                        .possible_values(&["text", "json"])
+                        .default_value("text"),
+                ),
         )
         .subcommand(SubCommand::with_name("symbols"))
+        .subcommand(
+            SubCommand::with_name("ssr")
+                .arg(Arg::with_name("rule").required(true))
+                .arg(
+                    Arg::with_name("files")
+                        .multiple(true)
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("memory")
+                .arg(
+                    Arg::with_name("files")
+                        .multiple(true)
+                        .required(true),
+                ),
+        )
         .get_matches();
     match matches.subcommand() {
         ("parse", Some(matches)) => {
             let start = Instant::now();
             let file = file()?;
             let elapsed = start.elapsed();
+            // The first walk creates red nodes, the second one reuses them.
+            let (cold_walk, n_elements) = time_walk(&file);
+            let (warm_walk, _) = time_walk(&file);
             if !matches.is_present("no-dump") {
-                println!("{}", syntax_tree(&file));
+                match matches.value_of("format").unwrap() {
+                    "json" => println!("{}", serde_json::to_string_pretty(file.syntax())?),
+                    _ => println!("{}", syntax_tree(&file)),
+                }
             }
             eprintln!("parsing: {:?}", elapsed);
+            eprintln!("walking {} elements: {:?} cold, {:?} warm", n_elements, cold_walk, warm_walk);
             ::std::mem::forget(file);
         }
         ("symbols", _) => {
@@ -55,6 +88,15 @@ fn main() -> Result<()> {
                 println!("{:?}", s);
             }
         }
+        ("ssr", Some(matches)) => {
+            let rule = matches.value_of("rule").unwrap();
+            let files = matches.values_of("files").unwrap();
+            structural_replace(rule, files)?;
+        }
+        ("memory", Some(matches)) => {
+            let files = matches.values_of("files").unwrap();
+            memory_report(files)?;
+        }
         ("render-test", Some(matches)) => {
             let file = matches.value_of("file").unwrap();
             let file = Path::new(file);
@@ -68,11 +110,49 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+fn time_walk(file: &File) -> (Duration, usize) {
+    let start = Instant::now();
+    let n_elements = walk::preorder_with_tokens(file.syntax_ref()).count();
+    (start.elapsed(), n_elements)
+}
+
 fn file() -> Result<File> {
     let text = read_stdin()?;
     Ok(libeditor::parse(&text))
 }
 
+/// Applies the search and replace `rule` to `files` in place.
+fn structural_replace<'a>(rule: &str, files: impl Iterator<Item = &'a str>) -> Result<()> {
+    let rule = match SsrRule::parse(rule) {
+        Ok(rule) => rule,
+        Err(e) => bail!("invalid rule: {}", e),
+    };
+    for path in files {
+        let text = fs::read_to_string(path)?;
+        if let Some(edit) = ssr(&File::parse(&text), &rule) {
+            fs::write(path, edit.apply(&text))?;
+            println!("{}", path);
+        }
+    }
+    Ok(())
+}
+
+/// Prints the size of the syntax trees of `files`, with and without
+/// sharing green nodes via `GreenInterner`.
+fn memory_report<'a>(files: impl Iterator<Item = &'a str>) -> Result<()> {
+    let mut interner = GreenInterner::new();
+    let mut plain = TreeMemory::new();
+    let mut interned = TreeMemory::new();
+    for path in files {
+        let text = fs::read_to_string(path)?;
+        let before = plain.add(File::parse(&text).syntax());
+        let after = interned.add(File::parse_with_interner(&text, &mut interner).syntax());
+        println!("{}: {} bytes, {} bytes interned", path, before, after);
+    }
+    println!("total: {} bytes, {} bytes interned", plain.total(), interned.total());
+    Ok(())
+}
+
 fn read_stdin() -> Result<String> {
     let mut buff = String::new();
     ::std::io::stdin().read_to_string(&mut buff)?;
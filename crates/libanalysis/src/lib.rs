@@ -13,6 +13,7 @@ mod symbol_index;
 
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
+use parking_lot::Mutex;
 
 use std::{
     sync::{
@@ -24,10 +25,10 @@ use std::{
 };
 
 use libsyntax2::{
-    TextUnit,
+    TextUnit, TextRange, AtomEdit, GreenInterner,
     ast::{self, AstNode},
 };
-use libeditor::{LineIndex, FileSymbol, find_node};
+use libeditor::{LineIndex, FileSymbol, Edit, SsrRule, find_node};
 
 use self::symbol_index::FileSymbols;
 pub use self::symbol_index::Query;
@@ -67,15 +68,18 @@ impl WorldState {
         let mut cnt = 0;
         for (id, text) in changes {
             cnt += 1;
-            data.file_map.remove(&id);
+            let old = data.file_map.remove(&id);
             if let Some(text) = text {
-                let file_data = FileData::new(text);
+                let file_data = match old {
+                    Some(old) => old.with_new_text(text),
+                    None => FileData::new(text, data.interner.clone()),
+                };
                 data.file_map.insert(id, Arc::new(file_data));
-            } else {
-                data.file_map.remove(&id);
             }
         }
         *data.unindexed.get_mut() += cnt;
+        // Drop the nodes used only by the old versions of the files.
+        data.interner.lock().maybe_gc();
     }
 
     fn data_mut(&mut self) -> &mut WorldData {
@@ -85,6 +89,7 @@ impl WorldState {
                     self.data.unindexed.load(SeqCst)
                 ),
                 file_map: self.data.file_map.clone(),
+                interner: self.data.interner.clone(),
             });
         }
         Arc::get_mut(&mut self.data).unwrap()
@@ -134,6 +139,18 @@ impl World {
         Ok(self.world_symbols(query).collect())
     }
 
+    /// Applies the search and replace `rule` to all files,
+    /// returning the edits of the files which have matches.
+    pub fn structural_replace(&self, rule: &SsrRule) -> Vec<(FileId, Edit)> {
+        self.data.file_map
+            .par_iter()
+            .filter_map(|(id, data)| {
+                let edit = libeditor::ssr(&data.syntax_transient(), rule)?;
+                Some((*id, edit))
+            })
+            .collect()
+    }
+
     fn reindex(&self) {
         let data = &*self.data;
         let unindexed = data.unindexed.load(SeqCst);
@@ -163,6 +180,7 @@ impl World {
 struct WorldData {
     unindexed: AtomicUsize,
     file_map: HashMap<FileId, Arc<FileData>>,
+    interner: Arc<Mutex<GreenInterner>>,
 }
 
 #[derive(Debug)]
@@ -171,26 +189,45 @@ struct FileData {
     symbols: OnceCell<FileSymbols>,
     syntax: OnceCell<ast::File>,
     lines: OnceCell<LineIndex>,
+    interner: Arc<Mutex<GreenInterner>>,
 }
 
 impl FileData {
-    fn new(text: String) -> FileData {
+    fn new(text: String, interner: Arc<Mutex<GreenInterner>>) -> FileData {
         FileData {
             text,
             symbols: OnceCell::new(),
             syntax: OnceCell::new(),
             lines: OnceCell::new(),
+            interner,
+        }
+    }
+
+    /// The next version of the file. If the old version has already been
+    /// parsed, the changed part of its tree is reparsed incrementally.
+    fn with_new_text(&self, text: String) -> FileData {
+        let res = FileData::new(text, self.interner.clone());
+        if let Some(syntax) = self.syntax.get() {
+            let edit = text_edit(&self.text, &res.text);
+            res.syntax.get_or_init(|| syntax.reparse(&edit));
         }
+        res
     }
 
     fn syntax(&self) -> &ast::File {
         self.syntax
-            .get_or_init(|| ast::File::parse(&self.text))
+            .get_or_init(|| self.parse())
     }
 
     fn syntax_transient(&self) -> ast::File {
         self.syntax.get().map(|s| s.clone())
-            .unwrap_or_else(|| ast::File::parse(&self.text))
+            .unwrap_or_else(|| self.parse())
+    }
+
+    fn parse(&self) -> ast::File {
+        // Files are parsed in parallel, so the shared interner is
+        // locked only while the tree is built, not while parsing.
+        ast::File::parse_with_shared_interner(&self.text, || self.interner.lock())
     }
 
     fn symbols(&self) -> &FileSymbols {
@@ -199,3 +236,24 @@ impl FileData {
             .get_or_init(|| FileSymbols::new(&syntax))
     }
 }
+
+/// The edit which turns `old` into `new`: the client sends the whole
+/// text of the file, so the changed range is recovered by skipping
+/// the common prefix and suffix.
+fn text_edit(old: &str, new: &str) -> AtomEdit {
+    let prefix: usize = old.chars()
+        .zip(new.chars())
+        .take_while(|&(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum();
+    let suffix: usize = old[prefix..].chars().rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|&(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum();
+    let delete = TextRange::from_to(
+        TextUnit::from(prefix as u32),
+        TextUnit::from((old.len() - suffix) as u32),
+    );
+    AtomEdit::replace(delete, new[prefix..new.len() - suffix].to_string())
+}
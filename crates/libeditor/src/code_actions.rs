@@ -1,11 +1,11 @@
 use {TextUnit, File, EditBuilder, Edit};
 use libsyntax2::{
-    ast::{self, AstNode, AttrsOwner},
+    ast::{self, make, AstNode, AttrsOwner},
     SyntaxKind::COMMA,
-    SyntaxNodeRef,
+    SyntaxNodeRef, SyntaxTokenRef, SyntaxElement,
     SyntaxRoot,
     algo::{
-        Direction, siblings,
+        Direction, non_trivia_sibling,
         find_leaf_at_offset, ancestors,
     },
 };
@@ -25,12 +25,13 @@ pub fn flip_comma<'a>(file: &'a File, offset: TextUnit) -> Option<impl FnOnce()
     let syntax = syntax.as_ref();
 
     let comma = find_leaf_at_offset(syntax, offset).find(|leaf| leaf.kind() == COMMA)?;
+    let comma = SyntaxElement::Token(comma);
     let left = non_trivia_sibling(comma, Direction::Backward)?;
     let right = non_trivia_sibling(comma, Direction::Forward)?;
     Some(move || {
         let mut edit = EditBuilder::new();
-        edit.replace(left.range(), right.text());
-        edit.replace(right.range(), left.text());
+        edit.replace(left.range(), right.text().to_string());
+        edit.replace(right.range(), left.text().to_string());
         ActionResult {
             edit: edit.finish(),
             cursor_position: CursorPosition::Same,
@@ -50,9 +51,10 @@ pub fn add_derive<'a>(file: &'a File, offset: TextUnit) -> Option<impl FnOnce()
         let mut edit = EditBuilder::new();
         let offset = match derive_attr {
             None => {
+                let attr = make::attr("derive()");
                 let node_start = nominal.syntax().range().start();
-                edit.insert(node_start, "#[derive()]\n".to_string());
-                node_start + TextUnit::of_str("#[derive(")
+                edit.insert(node_start, format!("{}\n", attr.syntax().text()));
+                node_start + attr.syntax().text().find(')').unwrap()
             }
             Some(tt) => {
                 tt.syntax().range().end() - TextUnit::of_char(')')
@@ -65,20 +67,14 @@ pub fn add_derive<'a>(file: &'a File, offset: TextUnit) -> Option<impl FnOnce()
     })
 }
 
-fn non_trivia_sibling(node: SyntaxNodeRef, direction: Direction) -> Option<SyntaxNodeRef> {
-    siblings(node, direction)
-        .skip(1)
-        .find(|node| !node.kind().is_trivia())
-}
-
-fn find_non_trivia_leaf(syntax: SyntaxNodeRef, offset: TextUnit) -> Option<SyntaxNodeRef> {
+fn find_non_trivia_leaf(syntax: SyntaxNodeRef, offset: TextUnit) -> Option<SyntaxTokenRef> {
     find_leaf_at_offset(syntax, offset)
         .find(|leaf| !leaf.kind().is_trivia())
 }
 
 pub fn find_node<'a, N: AstNode<&'a SyntaxRoot>>(syntax: SyntaxNodeRef<'a>, offset: TextUnit) -> Option<N> {
     let leaf = find_non_trivia_leaf(syntax, offset)?;
-    ancestors(leaf)
+    ancestors(leaf.parent())
         .filter_map(N::cast)
         .next()
 }
@@ -1,16 +1,11 @@
 use {TextRange, TextUnit};
+use libsyntax2::AtomEdit;
 
 #[derive(Debug, Clone)]
 pub struct Edit {
     atoms: Vec<AtomEdit>,
 }
 
-#[derive(Debug, Clone)]
-pub struct AtomEdit {
-    pub delete: TextRange,
-    pub insert: String,
-}
-
 #[derive(Debug)]
 pub struct EditBuilder {
     atoms: Vec<AtomEdit>
@@ -22,28 +17,46 @@ impl EditBuilder {
     }
 
     pub fn replace(&mut self, range: TextRange, replacement: String) {
-        self.atoms.push(AtomEdit { delete: range, insert: replacement })
+        self.atoms.push(AtomEdit::replace(range, replacement))
     }
 
     pub fn delete(&mut self, range: TextRange) {
-        self.replace(range, String::new());
+        self.atoms.push(AtomEdit::delete(range))
     }
 
     pub fn insert(&mut self, offset: TextUnit, text: String) {
-        self.replace(TextRange::offset_len(offset, 0.into()), text)
+        self.atoms.push(AtomEdit::insert(offset, text))
     }
 
     pub fn finish(self) -> Edit {
         let mut atoms = self.atoms;
         atoms.sort_by_key(|a| a.delete.start());
         for (a1, a2) in atoms.iter().zip(atoms.iter().skip(1)) {
-            assert!(a1.end() <= a2.start())
+            assert!(a1.delete.end() <= a2.delete.start())
         }
         Edit { atoms }
     }
 }
 
 impl Edit {
+    /// The smallest single-atom edit which turns `old` into `new`: the
+    /// common prefix and suffix are kept, everything else is replaced.
+    pub fn diff(old: &str, new: &str) -> Edit {
+        let prefix = common_len(old.chars(), new.chars());
+        let (old_rest, new_rest) = (&old[prefix..], &new[prefix..]);
+        let suffix = common_len(old_rest.chars().rev(), new_rest.chars().rev());
+        let mut edit = EditBuilder::new();
+        if old_rest.len() != suffix || new_rest.len() != suffix {
+            let delete = TextRange::from_to(
+                TextUnit::from(prefix as u32),
+                TextUnit::from((old.len() - suffix) as u32),
+            );
+            let insert = &new_rest[..new_rest.len() - suffix];
+            edit.replace(delete, insert.to_string());
+        }
+        edit.finish()
+    }
+
     pub fn into_atoms(self) -> Vec<AtomEdit> {
         self.atoms
     }
@@ -52,16 +65,16 @@ impl Edit {
         let mut total_len = text.len();
         for atom in self.atoms.iter() {
             total_len += atom.insert.len();
-            total_len -= atom.end() - atom.start();
+            total_len -= end(atom) - start(atom);
         }
         let mut buf = String::with_capacity(total_len);
         let mut prev = 0;
         for atom in self.atoms.iter() {
-            if atom.start() > prev {
-                buf.push_str(&text[prev..atom.start()]);
+            if start(atom) > prev {
+                buf.push_str(&text[prev..start(atom)]);
             }
             buf.push_str(&atom.insert);
-            prev = atom.end();
+            prev = end(atom);
         }
         buf.push_str(&text[prev..text.len()]);
         assert_eq!(buf.len(), total_len);
@@ -84,12 +97,18 @@ impl Edit {
     }
 }
 
-impl AtomEdit {
-    fn start(&self) -> usize {
-        u32::from(self.delete.start()) as usize
-    }
+fn start(atom: &AtomEdit) -> usize {
+    u32::from(atom.delete.start()) as usize
+}
 
-    fn end(&self) -> usize {
-        u32::from(self.delete.end()) as usize
-    }
+fn end(atom: &AtomEdit) -> usize {
+    u32::from(atom.delete.end()) as usize
+}
+
+/// Length in bytes of the common prefix of two char sequences.
+fn common_len(xs: impl Iterator<Item = char>, ys: impl Iterator<Item = char>) -> usize {
+    xs.zip(ys)
+        .take_while(|(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum()
 }
@@ -1,8 +1,8 @@
 use libsyntax2::{
     ast, AstNode,
-    TextRange, SyntaxNodeRef,
+    TextRange, SyntaxNodeRef, SyntaxElement,
     SyntaxKind::WHITESPACE,
-    algo::{find_leaf_at_offset, find_covering_node, ancestors},
+    algo::{find_leaf_at_offset, find_covering_element, ancestors},
 };
 
 pub fn extend_selection(file: &ast::File, range: TextRange) -> Option<TextRange> {
@@ -14,7 +14,7 @@ pub(crate) fn extend(root: SyntaxNodeRef, range: TextRange) -> Option<TextRange>
     if range.is_empty() {
         let offset = range.start();
         let mut leaves = find_leaf_at_offset(root, offset);
-        if let Some(leaf) = leaves.clone().find(|node| node.kind() != WHITESPACE) {
+        if let Some(leaf) = leaves.clone().find(|token| token.kind() != WHITESPACE) {
             return Some(leaf.range());
         }
         let ws = leaves.next()?;
@@ -32,7 +32,15 @@ pub(crate) fn extend(root: SyntaxNodeRef, range: TextRange) -> Option<TextRange>
 //        }
         return Some(ws.range());
     };
-    let node = find_covering_node(root, range);
+    let node = match find_covering_element(root, range) {
+        SyntaxElement::Token(token) => {
+            if token.range() != range {
+                return Some(token.range());
+            }
+            token.parent()
+        }
+        SyntaxElement::Node(node) => node,
+    };
 
     match ancestors(node).skip_while(|n| n.range() == range).next() {
         None => None,
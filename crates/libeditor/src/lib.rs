@@ -8,6 +8,7 @@ mod symbols;
 mod line_index;
 mod edit;
 mod code_actions;
+mod ssr;
 
 use libsyntax2::{
     ast::{self, NameOwner},
@@ -15,16 +16,17 @@ use libsyntax2::{
     algo::{walk, find_leaf_at_offset},
     SyntaxKind::{self, *},
 };
-pub use libsyntax2::{File, TextRange, TextUnit};
+pub use libsyntax2::{File, TextRange, TextUnit, AtomEdit, ErrorCode};
 pub use self::{
     line_index::{LineIndex, LineCol},
     extend_selection::extend_selection,
     symbols::{StructureNode, file_structure, FileSymbol, file_symbols},
-    edit::{EditBuilder, Edit, AtomEdit},
+    edit::{EditBuilder, Edit},
     code_actions::{
         ActionResult, CursorPosition, find_node,
         flip_comma, add_derive,
     },
+    ssr::{SsrRule, SsrError, ssr},
 };
 
 #[derive(Debug)]
@@ -37,6 +39,9 @@ pub struct HighlightedRange {
 pub struct Diagnostic {
     pub range: TextRange,
     pub msg: String,
+    /// `None` for the error nodes of the tree, which are always
+    /// accompanied by an error with a code.
+    pub code: Option<ErrorCode>,
 }
 
 #[derive(Debug)]
@@ -62,23 +67,23 @@ pub fn matching_brace(file: &ast::File, offset: TextUnit) -> Option<TextUnit> {
         L_PAREN, R_PAREN,
         L_ANGLE, R_ANGLE,
     ];
-    let (brace_node, brace_idx) = find_leaf_at_offset(file.syntax_ref(), offset)
-        .filter_map(|node| {
-            let idx = BRACES.iter().position(|&brace| brace == node.kind())?;
-            Some((node, idx))
+    let (brace_token, brace_idx) = find_leaf_at_offset(file.syntax_ref(), offset)
+        .filter_map(|token| {
+            let idx = BRACES.iter().position(|&brace| brace == token.kind())?;
+            Some((token, idx))
         })
         .next()?;
-    let parent = brace_node.parent()?;
+    let parent = brace_token.parent();
     let matching_kind = BRACES[brace_idx ^ 1];
-    let matching_node = parent.children()
-        .find(|node| node.kind() == matching_kind)?;
-    Some(matching_node.range().start())
+    let matching_token = parent.children_with_tokens()
+        .find(|element| element.kind() == matching_kind)?;
+    Some(matching_token.range().start())
 }
 
 pub fn highlight(file: &ast::File) -> Vec<HighlightedRange> {
     let mut res = Vec::new();
-    for node in walk::preorder(file.syntax_ref()) {
-        let tag = match node.kind() {
+    for element in walk::preorder_with_tokens(file.syntax_ref()) {
+        let tag = match element.kind() {
             ERROR => "error",
             COMMENT | DOC_COMMENT => "comment",
             STRING | RAW_STRING | RAW_BYTE_STRING | BYTE_STRING => "string",
@@ -91,7 +96,7 @@ pub fn highlight(file: &ast::File) -> Vec<HighlightedRange> {
             _ => continue,
         };
         res.push(HighlightedRange {
-            range: node.range(),
+            range: element.range(),
             tag,
         })
     }
@@ -101,17 +106,24 @@ pub fn highlight(file: &ast::File) -> Vec<HighlightedRange> {
 pub fn diagnostics(file: &ast::File) -> Vec<Diagnostic> {
     let mut res = Vec::new();
 
-    for node in walk::preorder(file.syntax_ref()) {
-        if node.kind() == ERROR {
+    for element in walk::preorder_with_tokens(file.syntax_ref()) {
+        if element.kind() == ERROR {
             res.push(Diagnostic {
-                range: node.range(),
+                range: element.range(),
                 msg: "Syntax Error".to_string(),
+                code: None,
             });
         }
     }
     res.extend(file.errors().into_iter().map(|err| Diagnostic {
-        range: TextRange::offset_len(err.offset, 1.into()),
+        // Errors from the parser point between the tokens.
+        range: if err.range.is_empty() {
+            TextRange::offset_len(err.range.start(), 1.into())
+        } else {
+            err.range
+        },
         msg: err.msg,
+        code: Some(err.code),
     }));
     res
 }
@@ -0,0 +1,322 @@
+//! Structural search and replace.
+//!
+//! A rule looks like `$a.unwrap_or(Default::default()) ==>> $a.unwrap_or_default()`.
+//! The pattern is parsed as an expression, with placeholders replaced by
+//! identifiers, and matched against the nodes of a file ignoring trivia.
+//! Each match is replaced with the template, where placeholders are
+//! substituted with the text of the matched nodes, parenthesized if
+//! the template expects an expression with a higher precedence.
+use std::{collections::HashMap, fmt};
+
+use {File, EditBuilder, Edit};
+use libsyntax2::{
+    ast::{self, AstNode},
+    SyntaxKind::*,
+    SyntaxNodeRef, SyntaxTokenRef, SyntaxElementRef, SyntaxElement,
+    algo::{walk, siblings_with_tokens, Direction},
+};
+
+const PLACEHOLDER_PREFIX: &str = "__ssr_placeholder_";
+
+#[derive(Debug)]
+pub struct SsrRule {
+    /// A file with a single function, which contains the pattern
+    /// as the initializer of a `let` statement.
+    pattern: File,
+    /// Same as `pattern`, for the template.
+    template: File,
+}
+
+#[derive(Debug)]
+pub struct SsrError(String);
+
+impl fmt::Display for SsrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl SsrRule {
+    pub fn parse(rule: &str) -> Result<SsrRule, SsrError> {
+        let mut parts = rule.split("==>>");
+        let (pattern, template) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(pattern), Some(template), None) => (pattern.trim(), template.trim()),
+            _ => return Err(SsrError("expected `pattern ==>> template`".to_string())),
+        };
+        let (pattern_text, placeholders) = replace_placeholders(pattern)?;
+        let pattern_file = parse_expr(&pattern_text)
+            .ok_or_else(|| SsrError(format!("pattern is not an expression: `{}`", pattern)))?;
+        if placeholder_name(expr_in_let(&pattern_file).unwrap()).is_some() {
+            return Err(SsrError("pattern should not be a single placeholder".to_string()));
+        }
+        let (template_text, template_placeholders) = replace_placeholders(template)?;
+        let template_file = parse_expr(&template_text)
+            .ok_or_else(|| SsrError(format!("template is not an expression: `{}`", template)))?;
+        if let Some(name) = template_placeholders.iter().find(|it| !placeholders.contains(it)) {
+            return Err(SsrError(format!("`${}` is not defined in the pattern", name)));
+        }
+        Ok(SsrRule { pattern: pattern_file, template: template_file })
+    }
+
+    fn pattern(&self) -> SyntaxNodeRef {
+        expr_in_let(&self.pattern).unwrap()
+    }
+
+    fn template(&self) -> SyntaxNodeRef {
+        expr_in_let(&self.template).unwrap()
+    }
+}
+
+/// Replaces all matches of the rule in `file`, `None` if there are
+/// no matches. Matches inside other matches are not replaced.
+pub fn ssr(file: &File, rule: &SsrRule) -> Option<Edit> {
+    let pattern = rule.pattern();
+    let template = rule.template();
+    let mut edit = EditBuilder::new();
+    let mut has_matches = false;
+    let mut last_end = 0.into();
+    for node in walk::preorder(file.syntax_ref()) {
+        if node.range().start() < last_end {
+            continue;
+        }
+        let mut bindings = HashMap::new();
+        if !match_node(pattern, node, &mut bindings) {
+            continue;
+        }
+        edit.replace(node.range(), instantiate(template, &bindings));
+        has_matches = true;
+        last_end = node.range().end();
+    }
+    if has_matches {
+        Some(edit.finish())
+    } else {
+        None
+    }
+}
+
+/// Replaces `$name` with identifiers, so that the text can be parsed.
+/// Returns the new text and the names of the placeholders.
+fn replace_placeholders(text: &str) -> Result<(String, Vec<String>), SsrError> {
+    let mut res = String::new();
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(idx) = rest.find('$') {
+        res.push_str(&rest[..idx]);
+        rest = &rest[idx + 1..];
+        let len = rest.find(|c: char| !(c == '_' || c.is_alphanumeric())).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(SsrError("`$` should be followed by a placeholder name".to_string()));
+        }
+        let name = &rest[..len];
+        res.push_str(PLACEHOLDER_PREFIX);
+        res.push_str(name);
+        names.push(name.to_string());
+        rest = &rest[len..];
+    }
+    res.push_str(rest);
+    Ok((res, names))
+}
+
+/// Parses `text` as the initializer of a `let` statement, `None` if
+/// it is not a single valid expression.
+fn parse_expr(text: &str) -> Option<File> {
+    let file = File::parse(&format!("fn __ssr() {{ let _ = {}; }}", text));
+    if !file.errors().is_empty() || expr_in_let(&file)?.text() != text {
+        return None;
+    }
+    Some(file)
+}
+
+fn expr_in_let(file: &File) -> Option<SyntaxNodeRef> {
+    let expr = walk::preorder(file.syntax_ref())
+        .filter_map(ast::LetStmt::cast)
+        .next()?
+        .initializer()?;
+    Some(*expr.syntax())
+}
+
+/// The name of the placeholder if the node consists of a single
+/// placeholder identifier, like the path expression `$a`.
+fn placeholder_name(node: SyntaxNodeRef) -> Option<String> {
+    let mut tokens = non_trivia_tokens(node);
+    let token = tokens.next()?;
+    if token.kind() != IDENT || tokens.next().is_some() {
+        return None;
+    }
+    if token.text().starts_with(PLACEHOLDER_PREFIX) {
+        Some(token.text()[PLACEHOLDER_PREFIX.len()..].to_string())
+    } else {
+        None
+    }
+}
+
+fn match_node<'a>(
+    pattern: SyntaxNodeRef,
+    code: SyntaxNodeRef<'a>,
+    bindings: &mut HashMap<String, SyntaxNodeRef<'a>>,
+) -> bool {
+    if let Some(name) = placeholder_name(pattern) {
+        if let Some(&bound) = bindings.get(&name) {
+            return same_tokens(bound, code);
+        }
+        bindings.insert(name, code);
+        return true;
+    }
+    if pattern.kind() != code.kind() {
+        return false;
+    }
+    let mut pattern_children = non_trivia_children(pattern);
+    let mut code_children = non_trivia_children(code);
+    loop {
+        match (pattern_children.next(), code_children.next()) {
+            (None, None) => return true,
+            (Some(SyntaxElement::Node(p)), Some(SyntaxElement::Node(c))) => {
+                if !match_node(p, c, bindings) {
+                    return false;
+                }
+            }
+            (Some(SyntaxElement::Token(p)), Some(SyntaxElement::Token(c))) => {
+                if p.kind() != c.kind() || p.text() != c.text() {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+    }
+}
+
+fn non_trivia_children<'a>(node: SyntaxNodeRef<'a>) -> impl Iterator<Item = SyntaxElementRef<'a>> {
+    node.first_child_or_token()
+        .into_iter()
+        .flat_map(|first| siblings_with_tokens(first, Direction::Forward))
+        .filter(|it| !it.kind().is_trivia())
+}
+
+fn non_trivia_tokens<'a>(node: SyntaxNodeRef<'a>) -> impl Iterator<Item = SyntaxTokenRef<'a>> {
+    walk::preorder_with_tokens(node).filter_map(|it| match it {
+        SyntaxElement::Token(it) if !it.kind().is_trivia() => Some(it),
+        _ => None,
+    })
+}
+
+/// Are the nodes equal, ignoring trivia?
+fn same_tokens(a: SyntaxNodeRef, b: SyntaxNodeRef) -> bool {
+    let mut a = non_trivia_tokens(a);
+    let mut b = non_trivia_tokens(b);
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return true,
+            (Some(a), Some(b)) if a.kind() == b.kind() && a.text() == b.text() => (),
+            _ => return false,
+        }
+    }
+}
+
+fn instantiate(template: SyntaxNodeRef, bindings: &HashMap<String, SyntaxNodeRef>) -> String {
+    let mut res = String::new();
+    for element in walk::preorder_with_tokens(template) {
+        let token = match element {
+            SyntaxElement::Token(it) => it,
+            SyntaxElement::Node(_) => continue,
+        };
+        // Placeholders may also be a part of an identifier, like in `with_$m`.
+        let mut rest = token.text().as_str();
+        while let Some(idx) = rest.find(PLACEHOLDER_PREFIX) {
+            res.push_str(&rest[..idx]);
+            rest = &rest[idx + PLACEHOLDER_PREFIX.len()..];
+            let len = rest.find(|c: char| !(c == '_' || c.is_alphanumeric())).unwrap_or(rest.len());
+            let bound = bindings[&rest[..len]];
+            if idx == 0 && len == rest.len() && needs_parens(token, bound) {
+                res.push_str(&format!("({})", bound.text()));
+            } else {
+                res.push_str(&bound.text().to_string());
+            }
+            rest = &rest[len..];
+        }
+        res.push_str(rest);
+    }
+    res
+}
+
+const POSTFIX_PRECEDENCE: u8 = 14;
+const PREFIX_PRECEDENCE: u8 = 13;
+const CAST_PRECEDENCE: u8 = 12;
+const RANGE_PRECEDENCE: u8 = 2;
+
+/// Should `expr` be parenthesized when it is substituted for the
+/// `placeholder` token of the template?
+fn needs_parens(placeholder: SyntaxTokenRef, expr: SyntaxNodeRef) -> bool {
+    let slot = placeholder.parent()
+        .ancestors()
+        .take_while(|it| it.range() == placeholder.range())
+        .last();
+    match slot {
+        Some(slot) if slot.kind() == PATH_EXPR => precedence(expr) < slot_precedence(slot),
+        _ => false,
+    }
+}
+
+/// The precedence of an expression, following the binding powers
+/// of the parser. Expressions which end with a block get the lowest
+/// one, because they may be parsed as statements.
+fn precedence(expr: SyntaxNodeRef) -> u8 {
+    match expr.kind() {
+        PATH_EXPR | PATH | NAME_REF | LITERAL | PAREN_EXPR | TUPLE_EXPR | ARRAY_EXPR
+        | CALL_EXPR | METHOD_CALL_EXPR | FIELD_EXPR | INDEX_EXPR | TRY_EXPR | AWAIT_EXPR
+        | MACRO_CALL | STRUCT_LIT => POSTFIX_PRECEDENCE,
+        PREFIX_EXPR | REF_EXPR | BOX_EXPR => PREFIX_PRECEDENCE,
+        CAST_EXPR => CAST_PRECEDENCE,
+        RANGE_EXPR => RANGE_PRECEDENCE,
+        BIN_EXPR => bin_op_bp(expr),
+        _ => 0,
+    }
+}
+
+/// The lowest precedence of an expression which can be used
+/// as the child `expr` of its parent without parentheses.
+fn slot_precedence(expr: SyntaxNodeRef) -> u8 {
+    let parent = match expr.parent() {
+        Some(it) => it,
+        None => return 0,
+    };
+    let is_first = parent.children().next().map(|it| it.range()) == Some(expr.range());
+    match parent.kind() {
+        METHOD_CALL_EXPR | FIELD_EXPR | TRY_EXPR | AWAIT_EXPR | CALL_EXPR | INDEX_EXPR
+            if is_first => POSTFIX_PRECEDENCE,
+        PREFIX_EXPR | REF_EXPR | BOX_EXPR => PREFIX_PRECEDENCE,
+        CAST_EXPR if is_first => CAST_PRECEDENCE,
+        RANGE_EXPR => RANGE_PRECEDENCE + 1,
+        BIN_EXPR => {
+            let bp = bin_op_bp(parent);
+            if is_first { bp } else { bp + 1 }
+        }
+        _ => 0,
+    }
+}
+
+/// Mirrors `current_op` of the expression grammar.
+fn bin_op_bp(bin_expr: SyntaxNodeRef) -> u8 {
+    let op = non_trivia_children(bin_expr)
+        .filter_map(|it| match it {
+            SyntaxElement::Token(it) => Some(it.kind()),
+            SyntaxElement::Node(_) => None,
+        })
+        .next();
+    let op = match op {
+        Some(it) => it,
+        None => return 0,
+    };
+    match op {
+        EQ | PLUSEQ | MINUSEQ | SHLEQ | SHREQ => 1,
+        PIPEPIPE => 3,
+        AMPAMP => 4,
+        EQEQ | NEQ | L_ANGLE | R_ANGLE | LTEQ | GTEQ => 5,
+        PIPE => 6,
+        CARET => 7,
+        AMP => 8,
+        SHL | SHR => 9,
+        MINUS | PLUS => 10,
+        STAR | SLASH | PERCENT => 11,
+        _ => 0,
+    }
+}
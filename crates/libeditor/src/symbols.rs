@@ -43,12 +43,14 @@ fn to_symbol(node: SyntaxNodeRef) -> Option<FileSymbol> {
     visitor()
         .visit(decl::<ast::FnDef<_>>)
         .visit(decl::<ast::StructDef<_>>)
+        .visit(decl::<ast::UnionDef<_>>)
         .visit(decl::<ast::EnumDef<_>>)
         .visit(decl::<ast::TraitDef<_>>)
         .visit(decl::<ast::Module<_>>)
         .visit(decl::<ast::TypeDef<_>>)
         .visit(decl::<ast::ConstDef<_>>)
         .visit(decl::<ast::StaticDef<_>>)
+        .visit(decl::<ast::MacroRules<_>>)
         .accept(node)?
 }
 
@@ -94,6 +96,7 @@ fn structure_node(node: SyntaxNodeRef) -> Option<StructureNode> {
     visitor()
         .visit(decl::<ast::FnDef<_>>)
         .visit(decl::<ast::StructDef<_>>)
+        .visit(decl::<ast::UnionDef<_>>)
         .visit(decl::<ast::NamedField<_>>)
         .visit(decl::<ast::EnumDef<_>>)
         .visit(decl::<ast::TraitDef<_>>)
@@ -101,6 +104,7 @@ fn structure_node(node: SyntaxNodeRef) -> Option<StructureNode> {
         .visit(decl::<ast::TypeDef<_>>)
         .visit(decl::<ast::ConstDef<_>>)
         .visit(decl::<ast::StaticDef<_>>)
+        .visit(decl::<ast::MacroRules<_>>)
         .visit(|im: ast::ImplItem<_>| {
             let target_type = im.target_type()?;
             let target_trait = im.target_trait();
@@ -7,9 +7,10 @@ extern crate assert_eq_text;
 use std::fmt;
 use itertools::Itertools;
 use libeditor::{
-    File, TextUnit, TextRange, ActionResult, CursorPosition,
+    File, TextUnit, TextRange, ActionResult, CursorPosition, Edit,
     highlight, runnables, extend_selection, file_structure,
-    flip_comma, add_derive, matching_brace,
+    flip_comma, add_derive, matching_brace, diagnostics,
+    SsrRule, ssr,
 };
 
 #[test]
@@ -44,6 +45,22 @@ fn main() {}
     );
 }
 
+#[test]
+fn test_diagnostics() {
+    let file = file(r#"
+fn main() {
+    let x = "\q";
+    let y = 92
+}
+"#);
+    let diagnostics = diagnostics(&file);
+    dbg_eq(
+        r#"[Diagnostic { range: [26; 28), msg: "unknown character escape", code: Some(InvalidEscape) },
+            Diagnostic { range: [45; 46), msg: "expected `;`", code: Some(ExpectedToken) }]"#,
+        &diagnostics,
+    );
+}
+
 #[test]
 fn test_runnables() {
     let file = file(r#"
@@ -84,6 +101,12 @@ const C: i32 = 92;
 impl E {}
 
 impl fmt::Debug for E {}
+
+macro_rules! mc {
+    () => {}
+}
+
+union U { a: i32 }
 "#);
     let symbols = file_structure(&file);
     dbg_eq(
@@ -96,7 +119,10 @@ impl fmt::Debug for E {}
             StructureNode { parent: None, label: "S", navigation_range: [96; 97), node_range: [89; 108), kind: STATIC_DEF },
             StructureNode { parent: None, label: "C", navigation_range: [115; 116), node_range: [109; 127), kind: CONST_DEF },
             StructureNode { parent: None, label: "impl E", navigation_range: [134; 135), node_range: [129; 138), kind: IMPL_ITEM },
-            StructureNode { parent: None, label: "impl fmt::Debug for E", navigation_range: [160; 161), node_range: [140; 164), kind: IMPL_ITEM }]"#,
+            StructureNode { parent: None, label: "impl fmt::Debug for E", navigation_range: [160; 161), node_range: [140; 164), kind: IMPL_ITEM },
+            StructureNode { parent: None, label: "mc", navigation_range: [179; 181), node_range: [166; 198), kind: MACRO_RULES },
+            StructureNode { parent: None, label: "U", navigation_range: [206; 207), node_range: [200; 218), kind: UNION_DEF },
+            StructureNode { parent: Some(11), label: "a", navigation_range: [210; 211), node_range: [210; 216), kind: NAMED_FIELD }]"#,
         &symbols,
     )
 }
@@ -124,6 +150,76 @@ fn test_add_derive() {
     );
 }
 
+#[test]
+fn test_edit_diff() {
+    fn do_check(old: &str, new: &str, expected: &str) {
+        let edit = Edit::diff(old, new);
+        assert_eq!(edit.apply(old), new);
+        dbg_eq(expected, &edit);
+    }
+
+    do_check("fn foo() {}", "fn foo() {}", "Edit { atoms: [] }");
+    do_check(
+        "fn foo(x: i32) {}",
+        "fn foo(x: i32, y: i32) {}",
+        r#"Edit { atoms: [AtomEdit { delete: [13; 13), insert: ", y: i32" }] }"#,
+    );
+    do_check(
+        "fn foo() { \"привет\" }",
+        "fn foo() { \"пока\" }",
+        r#"Edit { atoms: [AtomEdit { delete: [14; 24), insert: "ока" }] }"#,
+    );
+}
+
+#[test]
+fn test_ssr() {
+    fn do_check(rule: &str, before: &str, after: &str) {
+        let rule = SsrRule::parse(rule).unwrap();
+        let file = file(before);
+        let actual = match ssr(&file, &rule) {
+            Some(edit) => edit.apply(before),
+            None => before.to_string(),
+        };
+        assert_eq_text!(after, &actual);
+    }
+
+    do_check(
+        "$a.unwrap_or(Default::default()) ==>> $a.unwrap_or_default()",
+        "fn f() { let x = foo(1).unwrap_or( Default :: default() ); }",
+        "fn f() { let x = foo(1).unwrap_or_default(); }",
+    );
+    do_check(
+        "$a.unwrap() ==>> $a?",
+        "fn f() { a.unwrap(); b.c.unwrap().d; a.unwrap_or(1); }",
+        "fn f() { a?; b.c?.d; a.unwrap_or(1); }",
+    );
+    do_check(
+        "$a + $a ==>> 2 * $a",
+        "fn f() { x + x; x + y; foo(1) + foo( 1 ); }",
+        "fn f() { 2 * x; x + y; 2 * foo(1); }",
+    );
+    do_check(
+        "Builder::new().$m($x) ==>> Builder::with_$m($x)",
+        "fn f() { Builder::new().name(\"a\"); }",
+        "fn f() { Builder::with_name(\"a\"); }",
+    );
+    do_check(
+        "foo($a) ==>> $a.bar()",
+        "fn f() { foo(x + y); foo(-x); foo(x.y); }",
+        "fn f() { (x + y).bar(); (-x).bar(); x.y.bar(); }",
+    );
+    do_check(
+        "double($a) ==>> $a * 2 + 1",
+        "fn f() { double(x + 1); double(x * 3); double(x as u8); double(-x); }",
+        "fn f() { (x + 1) * 2 + 1; x * 3 * 2 + 1; x as u8 * 2 + 1; -x * 2 + 1; }",
+    );
+
+    assert!(SsrRule::parse("$a.unwrap()").is_err());
+    assert!(SsrRule::parse("$a.unwrap() ==>> $b?").is_err());
+    assert!(SsrRule::parse("fn $a() {} ==>> $a").is_err());
+    assert!(SsrRule::parse("$a ==>> $a").is_err());
+}
+
 #[test]
 fn test_matching_brace() {
     fn do_check(before: &str, after: &str) {
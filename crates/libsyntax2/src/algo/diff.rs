@@ -0,0 +1,204 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    iter,
+    sync::Arc,
+};
+
+use {
+    yellow::{GreenNode, GreenBranch},
+    SyntaxNodeRef, SyntaxElementRef, SyntaxElement, TextRange,
+};
+
+/// Children lists longer than this are not aligned with LCS, to
+/// avoid the quadratic blowup: their children are paired positionally.
+const MAX_LCS_CELLS: usize = 1 << 20;
+
+/// The difference between two syntax trees, as a list of edits in
+/// the order of the text.
+#[derive(Debug, Clone)]
+pub struct TreeDiff<'a> {
+    pub edits: Vec<DiffEdit<'a>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DiffEdit<'a> {
+    /// An element of the new tree which has no counterpart in the old one.
+    Insert(SyntaxElementRef<'a>),
+    /// An element of the old tree which has no counterpart in the new one.
+    Delete(SyntaxElementRef<'a>),
+    /// An element which has changed, but could not be diffed further,
+    /// because it is a token or because its kind has changed.
+    Replace {
+        old: SyntaxElementRef<'a>,
+        new: SyntaxElementRef<'a>,
+    },
+}
+
+impl<'a> TreeDiff<'a> {
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// Ranges of the new tree which were inserted or replaced.
+    pub fn changed_ranges(&self) -> Vec<TextRange> {
+        self.edits.iter()
+            .filter_map(|edit| match *edit {
+                DiffEdit::Insert(new) | DiffEdit::Replace { new, .. } => Some(new.range()),
+                DiffEdit::Delete(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Computes the difference between two versions of a tree.
+///
+/// Subtrees are compared by identity of their green nodes first, so
+/// the subtrees shared after an incremental reparse are skipped
+/// immediately. Other subtrees are compared by hash. Children of
+/// changed nodes are aligned using the longest common subsequence of
+/// unchanged children.
+pub fn diff<'a>(old: SyntaxNodeRef<'a>, new: SyntaxNodeRef<'a>) -> TreeDiff<'a> {
+    let mut differ = Differ { hashes: HashMap::new(), edits: Vec::new() };
+    differ.diff_elements(SyntaxElement::Node(old), SyntaxElement::Node(new));
+    TreeDiff { edits: differ.edits }
+}
+
+struct Differ<'a> {
+    hashes: HashMap<*const GreenBranch, u64>,
+    edits: Vec<DiffEdit<'a>>,
+}
+
+impl<'a> Differ<'a> {
+    fn diff_elements(&mut self, old: SyntaxElementRef<'a>, new: SyntaxElementRef<'a>) {
+        if self.same(old.green(), new.green()) {
+            return;
+        }
+        match (old, new) {
+            (SyntaxElement::Node(old), SyntaxElement::Node(new)) if old.kind() == new.kind() => {
+                self.diff_children(old, new)
+            }
+            _ => self.edits.push(DiffEdit::Replace { old, new }),
+        }
+    }
+
+    fn diff_children(&mut self, old: SyntaxNodeRef<'a>, new: SyntaxNodeRef<'a>) {
+        let old = children(old);
+        let new = children(new);
+
+        let mut prefix = 0;
+        while prefix < old.len().min(new.len())
+            && self.same(old[prefix].green(), new[prefix].green()) {
+            prefix += 1;
+        }
+        let mut suffix = 0;
+        while suffix < old.len().min(new.len()) - prefix
+            && self.same(old[old.len() - suffix - 1].green(), new[new.len() - suffix - 1].green()) {
+            suffix += 1;
+        }
+        let old = &old[prefix..old.len() - suffix];
+        let new = &new[prefix..new.len() - suffix];
+
+        let (mut i, mut j) = (0, 0);
+        let anchors = self.lcs(old, new);
+        for (next_i, next_j) in anchors.into_iter().chain(iter::once((old.len(), new.len()))) {
+            self.diff_gap(&old[i..next_i], &new[j..next_j]);
+            i = next_i + 1;
+            j = next_j + 1;
+        }
+    }
+
+    /// Diffs the changed children between two unchanged ones.
+    fn diff_gap(&mut self, old: &[SyntaxElementRef<'a>], new: &[SyntaxElementRef<'a>]) {
+        let n_paired = old.len().min(new.len());
+        for (&old, &new) in old.iter().zip(new.iter()) {
+            self.diff_elements(old, new);
+        }
+        for &old in old[n_paired..].iter() {
+            self.edits.push(DiffEdit::Delete(old));
+        }
+        for &new in new[n_paired..].iter() {
+            self.edits.push(DiffEdit::Insert(new));
+        }
+    }
+
+    /// Indices of the unchanged elements, which are matched between
+    /// `old` and `new`.
+    fn lcs(&mut self, old: &[SyntaxElementRef<'a>], new: &[SyntaxElementRef<'a>]) -> Vec<(usize, usize)> {
+        if old.is_empty() || new.is_empty() || old.len() * new.len() > MAX_LCS_CELLS {
+            return Vec::new();
+        }
+        let width = new.len() + 1;
+        // `table[i * width + j]` is the length of LCS of `old[i..]` and `new[j..]`.
+        let mut table = vec![0u32; (old.len() + 1) * width];
+        for i in (0..old.len()).rev() {
+            for j in (0..new.len()).rev() {
+                table[i * width + j] = if self.same(old[i].green(), new[j].green()) {
+                    table[(i + 1) * width + j + 1] + 1
+                } else {
+                    table[(i + 1) * width + j].max(table[i * width + j + 1])
+                };
+            }
+        }
+        let mut res = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < old.len() && j < new.len() {
+            if self.same(old[i].green(), new[j].green()) {
+                res.push((i, j));
+                i += 1;
+                j += 1;
+            } else if table[(i + 1) * width + j] >= table[i * width + j + 1] {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        res
+    }
+
+    fn same(&mut self, old: &GreenNode, new: &GreenNode) -> bool {
+        match (old, new) {
+            (GreenNode::Branch(old), GreenNode::Branch(new)) if Arc::ptr_eq(old, new) => true,
+            (GreenNode::Leaf { .. }, GreenNode::Leaf { .. }) => {
+                old.kind() == new.kind() && old.leaf_text() == new.leaf_text()
+            }
+            (GreenNode::Branch(_), GreenNode::Branch(_)) => {
+                old.kind() == new.kind()
+                    && old.text_len() == new.text_len()
+                    && self.hash(old) == self.hash(new)
+            }
+            _ => false,
+        }
+    }
+
+    fn hash(&mut self, green: &GreenNode) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        green.kind().hash(&mut hasher);
+        match green {
+            GreenNode::Leaf { text, .. } => text.hash(&mut hasher),
+            GreenNode::Branch(branch) => {
+                let key = &**branch as *const GreenBranch;
+                if let Some(&hash) = self.hashes.get(&key) {
+                    return hash;
+                }
+                for child in branch.children() {
+                    self.hash(child).hash(&mut hasher);
+                }
+                let hash = hasher.finish();
+                self.hashes.insert(key, hash);
+                return hash;
+            }
+        }
+        hasher.finish()
+    }
+}
+
+fn children(node: SyntaxNodeRef) -> Vec<SyntaxElementRef> {
+    let mut res = Vec::new();
+    let mut child = node.first_child_or_token();
+    while let Some(element) = child {
+        res.push(element);
+        child = element.next_sibling_or_token();
+    }
+    res
+}
@@ -1,9 +1,15 @@
 pub mod walk;
 pub mod visit;
+mod diff;
 
-use {SyntaxNodeRef, TextUnit, TextRange};
+use {
+    AstNode, SyntaxNode, SyntaxToken, SyntaxElement, TreeRoot, TextUnit, TextRange,
+    text_utils::{contains_offset_nonstrict, is_subrange},
+};
 
-pub fn find_leaf_at_offset(node: SyntaxNodeRef, offset: TextUnit) -> LeafAtOffset {
+pub use self::diff::{diff, TreeDiff, DiffEdit};
+
+pub fn find_leaf_at_offset<R: TreeRoot>(node: SyntaxNode<R>, offset: TextUnit) -> LeafAtOffset<SyntaxToken<R>> {
     let range = node.range();
     assert!(
         contains_offset_nonstrict(range, offset),
@@ -13,11 +19,7 @@ pub fn find_leaf_at_offset(node: SyntaxNodeRef, offset: TextUnit) -> LeafAtOffse
         return LeafAtOffset::None;
     }
 
-    if node.is_leaf() {
-        return LeafAtOffset::Single(node);
-    }
-
-    let mut children = node.children()
+    let mut children = node.children_with_tokens()
         .filter(|child| {
             let child_range = child.range();
             !child_range.is_empty() && contains_offset_nonstrict(child_range, offset)
@@ -27,104 +29,153 @@ pub fn find_leaf_at_offset(node: SyntaxNodeRef, offset: TextUnit) -> LeafAtOffse
     let right = children.next();
     assert!(children.next().is_none());
     return if let Some(right) = right {
-        match (find_leaf_at_offset(left, offset), find_leaf_at_offset(right, offset)) {
+        match (leaf_at_offset(left, offset), leaf_at_offset(right, offset)) {
             (LeafAtOffset::Single(left), LeafAtOffset::Single(right)) =>
                 LeafAtOffset::Between(left, right),
             _ => unreachable!()
         }
     } else {
-        find_leaf_at_offset(left, offset)
+        leaf_at_offset(left, offset)
     };
+
+    fn leaf_at_offset<R: TreeRoot>(element: SyntaxElement<R>, offset: TextUnit) -> LeafAtOffset<SyntaxToken<R>> {
+        match element {
+            SyntaxElement::Token(token) => LeafAtOffset::Single(token),
+            SyntaxElement::Node(node) => find_leaf_at_offset(node, offset),
+        }
+    }
 }
 
+/// The tokens at an offset: there may be two of them if the
+/// offset is at the boundary between the tokens.
 #[derive(Clone, Copy, Debug)]
-pub enum LeafAtOffset<'a> {
+pub enum LeafAtOffset<T> {
     None,
-    Single(SyntaxNodeRef<'a>),
-    Between(SyntaxNodeRef<'a>, SyntaxNodeRef<'a>)
+    Single(T),
+    Between(T, T)
 }
 
-impl<'a> LeafAtOffset<'a> {
-    pub fn right_biased(self) -> Option<SyntaxNodeRef<'a>> {
+impl<T> LeafAtOffset<T> {
+    pub fn right_biased(self) -> Option<T> {
         match self {
             LeafAtOffset::None => None,
-            LeafAtOffset::Single(node) => Some(node),
+            LeafAtOffset::Single(token) => Some(token),
             LeafAtOffset::Between(_, right) => Some(right)
         }
     }
 
-    pub fn left_biased(self) -> Option<SyntaxNodeRef<'a>> {
+    pub fn left_biased(self) -> Option<T> {
         match self {
             LeafAtOffset::None => None,
-            LeafAtOffset::Single(node) => Some(node),
+            LeafAtOffset::Single(token) => Some(token),
             LeafAtOffset::Between(left, _) => Some(left)
         }
     }
 }
 
-impl<'f> Iterator for LeafAtOffset<'f> {
-    type Item = SyntaxNodeRef<'f>;
+impl<T> Iterator for LeafAtOffset<T> {
+    type Item = T;
 
-    fn next(&mut self) -> Option<SyntaxNodeRef<'f>> {
-        match *self {
+    fn next(&mut self) -> Option<T> {
+        match ::std::mem::replace(self, LeafAtOffset::None) {
             LeafAtOffset::None => None,
-            LeafAtOffset::Single(node) => { *self = LeafAtOffset::None; Some(node) }
+            LeafAtOffset::Single(token) => Some(token),
             LeafAtOffset::Between(left, right) => { *self = LeafAtOffset::Single(right); Some(left) }
         }
     }
 }
 
-pub fn find_covering_node(root: SyntaxNodeRef, range: TextRange) -> SyntaxNodeRef {
+/// Finds the smallest node or token which fully contains `range`.
+pub fn find_covering_element<R: TreeRoot>(root: SyntaxNode<R>, range: TextRange) -> SyntaxElement<R> {
     assert!(is_subrange(root.range(), range));
     let (left, right) = match (
-        find_leaf_at_offset(root, range.start()).right_biased(),
-        find_leaf_at_offset(root, range.end()).left_biased()
+        find_leaf_at_offset(root.clone(), range.start()).right_biased(),
+        find_leaf_at_offset(root.clone(), range.end()).left_biased()
     ) {
         (Some(l), Some(r)) => (l, r),
-        _ => return root
+        _ => return SyntaxElement::Node(root)
     };
+    if left == right {
+        return SyntaxElement::Token(left);
+    }
+    SyntaxElement::Node(common_ancestor(left.parent(), right.parent()))
+}
+
+/// Finds the smallest node which fully contains `range`.
+pub fn find_covering_node<R: TreeRoot>(root: SyntaxNode<R>, range: TextRange) -> SyntaxNode<R> {
+    match find_covering_element(root, range) {
+        SyntaxElement::Node(node) => node,
+        SyntaxElement::Token(token) => token.parent(),
+    }
+}
+
+/// Finds the smallest node of type `N` which fully contains `range`.
+pub fn find_node_at_range<R: TreeRoot, N: AstNode<R>>(root: SyntaxNode<R>, range: TextRange) -> Option<N> {
+    ancestors(find_covering_node(root, range))
+        .filter_map(N::cast)
+        .next()
+}
 
-    common_ancestor(left, right)
+pub fn ancestors<R: TreeRoot>(node: SyntaxNode<R>) -> impl Iterator<Item=SyntaxNode<R>> {
+    generate(Some(node), |node| node.parent())
 }
 
-pub fn ancestors<'a>(node: SyntaxNodeRef<'a>) -> impl Iterator<Item=SyntaxNodeRef<'a>> {
-    generate(Some(node), |&node| node.parent())
+/// The node itself and all nodes below it, in preorder.
+pub fn descendants<R: TreeRoot>(node: SyntaxNode<R>) -> walk::Preorder<R> {
+    walk::preorder(node)
 }
 
-#[derive(Debug)]
+/// Like `descendants`, but also yields tokens.
+pub fn descendants_with_tokens<R: TreeRoot>(node: SyntaxNode<R>) -> impl Iterator<Item=SyntaxElement<R>> {
+    walk::preorder_with_tokens(node)
+}
+
+#[derive(Debug, Clone, Copy)]
 pub enum Direction {
     Forward,
     Backward,
 }
 
-pub fn siblings<'a>(
-    node: SyntaxNodeRef<'a>,
+pub fn siblings<R: TreeRoot>(
+    node: SyntaxNode<R>,
     direction: Direction
-) -> impl Iterator<Item=SyntaxNodeRef<'a>> {
-    generate(Some(node), move |&node| match direction {
+) -> impl Iterator<Item=SyntaxNode<R>> {
+    generate(Some(node), move |node| match direction {
         Direction::Forward => node.next_sibling(),
         Direction::Backward => node.prev_sibling(),
     })
 }
 
-fn common_ancestor<'a>(n1: SyntaxNodeRef<'a>, n2: SyntaxNodeRef<'a>) -> SyntaxNodeRef<'a> {
-    for p in ancestors(n1) {
-        if ancestors(n2).any(|a| a == p) {
-            return p;
-        }
-    }
-    panic!("Can't find common ancestor of {:?} and {:?}", n1, n2)
+pub fn siblings_with_tokens<R: TreeRoot>(
+    element: SyntaxElement<R>,
+    direction: Direction
+) -> impl Iterator<Item=SyntaxElement<R>> {
+    generate(Some(element), move |element| match direction {
+        Direction::Forward => element.next_sibling_or_token(),
+        Direction::Backward => element.prev_sibling_or_token(),
+    })
 }
 
-fn contains_offset_nonstrict(range: TextRange, offset: TextUnit) -> bool {
-    range.start() <= offset && offset <= range.end()
+/// The closest sibling in `direction` which is not a whitespace or a comment.
+pub fn non_trivia_sibling<R: TreeRoot>(
+    element: SyntaxElement<R>,
+    direction: Direction
+) -> Option<SyntaxElement<R>> {
+    siblings_with_tokens(element, direction)
+        .skip(1)
+        .find(|element| !element.kind().is_trivia())
 }
 
-fn is_subrange(range: TextRange, subrange: TextRange) -> bool {
-    range.start() <= subrange.start() && subrange.end() <= range.end()
+fn common_ancestor<R: TreeRoot>(n1: SyntaxNode<R>, n2: SyntaxNode<R>) -> SyntaxNode<R> {
+    for p in ancestors(n1.clone()) {
+        if ancestors(n2.clone()).any(|a| a == p) {
+            return p;
+        }
+    }
+    panic!("Can't find common ancestor of {:?} and {:?}", n1, n2)
 }
 
-fn generate<T>(seed: Option<T>, step: impl Fn(&T) -> Option<T>) -> impl Iterator<Item=T> {
+pub(crate) fn generate<T>(seed: Option<T>, step: impl Fn(&T) -> Option<T>) -> impl Iterator<Item=T> {
     ::itertools::unfold(seed, move |slot| {
         slot.take().map(|curr| {
             *slot = step(&curr);
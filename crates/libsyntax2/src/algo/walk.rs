@@ -1,38 +1,112 @@
 use {
-    SyntaxNodeRef,
+    SyntaxNode, SyntaxElement, TreeRoot,
     algo::generate,
 };
 
-pub fn preorder<'a>(root: SyntaxNodeRef<'a>) -> impl Iterator<Item = SyntaxNodeRef<'a>> {
-    walk(root).filter_map(|event| match event {
-        WalkEvent::Enter(node) => Some(node),
+/// Iterates over the subtree of `root`, parents before children.
+pub fn preorder<R: TreeRoot>(root: SyntaxNode<R>) -> Preorder<R> {
+    Preorder { root: root.clone(), next: Some(WalkEvent::Enter(root)) }
+}
+
+pub fn preorder_with_tokens<R: TreeRoot>(root: SyntaxNode<R>) -> impl Iterator<Item = SyntaxElement<R>> {
+    walk_with_tokens(root).filter_map(|event| match event {
+        WalkEvent::Enter(element) => Some(element),
         WalkEvent::Exit(_) => None,
     })
 }
 
+/// Iterates over the subtree of `root`, children before parents.
+pub fn postorder<R: TreeRoot>(root: SyntaxNode<R>) -> impl Iterator<Item = SyntaxNode<R>> {
+    walk(root).filter_map(|event| match event {
+        WalkEvent::Enter(_) => None,
+        WalkEvent::Exit(node) => Some(node),
+    })
+}
+
 #[derive(Debug, Copy, Clone)]
-pub enum WalkEvent<'a> {
-    Enter(SyntaxNodeRef<'a>),
-    Exit(SyntaxNodeRef<'a>),
+pub enum WalkEvent<T> {
+    Enter(T),
+    Exit(T),
+}
+
+pub fn walk<R: TreeRoot>(root: SyntaxNode<R>) -> impl Iterator<Item = WalkEvent<SyntaxNode<R>>> {
+    generate(Some(WalkEvent::Enter(root.clone())), move |pos| next_event(&root, pos))
 }
 
-pub fn walk<'a>(root: SyntaxNodeRef<'a>) -> impl Iterator<Item = WalkEvent<'a>> {
-    generate(Some(WalkEvent::Enter(root)), |pos| {
-        let next = match *pos {
-            WalkEvent::Enter(node) => match node.first_child() {
+pub fn walk_with_tokens<R: TreeRoot>(root: SyntaxNode<R>) -> impl Iterator<Item = WalkEvent<SyntaxElement<R>>> {
+    let root = SyntaxElement::Node(root);
+    generate(Some(WalkEvent::Enter(root.clone())), move |pos| {
+        let next = match pos {
+            WalkEvent::Enter(SyntaxElement::Node(node)) => match node.first_child_or_token() {
                 Some(child) => WalkEvent::Enter(child),
-                None => WalkEvent::Exit(node),
+                None => WalkEvent::Exit(SyntaxElement::Node(node.clone())),
             },
-            WalkEvent::Exit(node) => {
-                match node.next_sibling() {
+            WalkEvent::Enter(token) => WalkEvent::Exit(token.clone()),
+            WalkEvent::Exit(element) => {
+                if *element == root {
+                    return None;
+                }
+                match element.next_sibling_or_token() {
                     Some(sibling) => WalkEvent::Enter(sibling),
-                    None => match node.parent() {
-                        Some(node) => WalkEvent::Exit(node),
-                        None => return None,
-                    },
+                    None => WalkEvent::Exit(SyntaxElement::Node(element.parent().unwrap())),
                 }
             }
         };
         Some(next)
     })
 }
+
+/// A preorder iterator, which can skip the children of the current node.
+#[derive(Debug, Clone)]
+pub struct Preorder<R: TreeRoot> {
+    root: SyntaxNode<R>,
+    next: Option<WalkEvent<SyntaxNode<R>>>,
+}
+
+impl<R: TreeRoot> Preorder<R> {
+    /// Don't visit the descendants of the node returned last.
+    pub fn skip_subtree(&mut self) {
+        self.next = match self.next.take() {
+            Some(WalkEvent::Enter(first_child)) => {
+                Some(WalkEvent::Exit(first_child.parent().unwrap()))
+            }
+            next => next,
+        };
+    }
+}
+
+impl<R: TreeRoot> Iterator for Preorder<R> {
+    type Item = SyntaxNode<R>;
+
+    fn next(&mut self) -> Option<SyntaxNode<R>> {
+        while let Some(event) = self.next.take() {
+            self.next = next_event(&self.root, &event);
+            if let WalkEvent::Enter(node) = event {
+                return Some(node);
+            }
+        }
+        None
+    }
+}
+
+fn next_event<R: TreeRoot>(
+    root: &SyntaxNode<R>,
+    pos: &WalkEvent<SyntaxNode<R>>,
+) -> Option<WalkEvent<SyntaxNode<R>>> {
+    let next = match pos {
+        WalkEvent::Enter(node) => match node.first_child() {
+            Some(child) => WalkEvent::Enter(child),
+            None => WalkEvent::Exit(node.clone()),
+        },
+        WalkEvent::Exit(node) => {
+            if node == root {
+                return None;
+            }
+            match node.next_sibling() {
+                Some(sibling) => WalkEvent::Enter(sibling),
+                None => WalkEvent::Exit(node.parent().unwrap()),
+            }
+        }
+    };
+    Some(next)
+}
@@ -5,471 +5,2095 @@ use {
     SyntaxKind::*,
 };
 
-// ArrayType
+// Alias
 #[derive(Debug, Clone, Copy)]
-pub struct ArrayType<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct Alias<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for ArrayType<R> {
+impl<R: TreeRoot> AstNode<R> for Alias<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            ARRAY_TYPE => Some(ArrayType { syntax }),
+            ALIAS => Some(Alias { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ArrayType<R> {}
+impl<R: TreeRoot> ast::NameOwner<R> for Alias<R> {}
+impl<R: TreeRoot> Alias<R> {}
 
-// Attr
+// ArgList
 #[derive(Debug, Clone, Copy)]
-pub struct Attr<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct ArgList<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for Attr<R> {
+impl<R: TreeRoot> AstNode<R> for ArgList<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            ATTR => Some(Attr { syntax }),
+            ARG_LIST => Some(ArgList { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> Attr<R> {
-    pub fn value(&self) -> Option<TokenTree<R>> {
+impl<R: TreeRoot> ArgList<R> {
+    pub fn args<'a>(&'a self) -> impl Iterator<Item = Expr<R>> + 'a {
         self.syntax()
             .children()
-            .filter_map(TokenTree::cast)
-            .next()
+            .filter_map(Expr::cast)
     }
 }
 
-// ConstDef
+// ArrayExpr
 #[derive(Debug, Clone, Copy)]
-pub struct ConstDef<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct ArrayExpr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for ConstDef<R> {
+impl<R: TreeRoot> AstNode<R> for ArrayExpr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            CONST_DEF => Some(ConstDef { syntax }),
+            ARRAY_EXPR => Some(ArrayExpr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ast::NameOwner<R> for ConstDef<R> {}
-impl<R: TreeRoot> ast::AttrsOwner<R> for ConstDef<R> {}
-impl<R: TreeRoot> ConstDef<R> {}
+impl<R: TreeRoot> ArrayExpr<R> {}
 
-// DynTraitType
+// ArrayType
 #[derive(Debug, Clone, Copy)]
-pub struct DynTraitType<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct ArrayType<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for DynTraitType<R> {
+impl<R: TreeRoot> AstNode<R> for ArrayType<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            DYN_TRAIT_TYPE => Some(DynTraitType { syntax }),
+            ARRAY_TYPE => Some(ArrayType { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> DynTraitType<R> {}
+impl<R: TreeRoot> ArrayType<R> {}
 
-// EnumDef
+// AsyncBlockExpr
 #[derive(Debug, Clone, Copy)]
-pub struct EnumDef<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct AsyncBlockExpr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for EnumDef<R> {
+impl<R: TreeRoot> AstNode<R> for AsyncBlockExpr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            ENUM_DEF => Some(EnumDef { syntax }),
+            ASYNC_BLOCK_EXPR => Some(AsyncBlockExpr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ast::NameOwner<R> for EnumDef<R> {}
-impl<R: TreeRoot> ast::AttrsOwner<R> for EnumDef<R> {}
-impl<R: TreeRoot> EnumDef<R> {}
+impl<R: TreeRoot> AsyncBlockExpr<R> {
+    pub fn block(&self) -> Option<BlockExpr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(BlockExpr::cast)
+            .next()
+    }
+}
 
-// File
+// Attr
 #[derive(Debug, Clone, Copy)]
-pub struct File<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct Attr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for File<R> {
+impl<R: TreeRoot> AstNode<R> for Attr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            FILE => Some(File { syntax }),
+            ATTR => Some(Attr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> File<R> {
-    pub fn functions<'a>(&'a self) -> impl Iterator<Item = FnDef<R>> + 'a {
+impl<R: TreeRoot> Attr<R> {
+    pub fn value(&self) -> Option<TokenTree<R>> {
         self.syntax()
             .children()
-            .filter_map(FnDef::cast)
+            .filter_map(TokenTree::cast)
+            .next()
     }
 }
 
-// FnDef
+// AwaitExpr
 #[derive(Debug, Clone, Copy)]
-pub struct FnDef<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct AwaitExpr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for FnDef<R> {
+impl<R: TreeRoot> AstNode<R> for AwaitExpr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            FN_DEF => Some(FnDef { syntax }),
+            AWAIT_EXPR => Some(AwaitExpr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ast::NameOwner<R> for FnDef<R> {}
-impl<R: TreeRoot> ast::AttrsOwner<R> for FnDef<R> {}
-impl<R: TreeRoot> FnDef<R> {}
+impl<R: TreeRoot> AwaitExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
 
-// FnPointerType
+// BinExpr
 #[derive(Debug, Clone, Copy)]
-pub struct FnPointerType<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct BinExpr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for FnPointerType<R> {
+impl<R: TreeRoot> AstNode<R> for BinExpr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            FN_POINTER_TYPE => Some(FnPointerType { syntax }),
+            BIN_EXPR => Some(BinExpr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> FnPointerType<R> {}
+impl<R: TreeRoot> BinExpr<R> {}
 
-// ForType
+// BindPat
 #[derive(Debug, Clone, Copy)]
-pub struct ForType<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct BindPat<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for ForType<R> {
+impl<R: TreeRoot> AstNode<R> for BindPat<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            FOR_TYPE => Some(ForType { syntax }),
+            BIND_PAT => Some(BindPat { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ForType<R> {}
+impl<R: TreeRoot> ast::NameOwner<R> for BindPat<R> {}
+impl<R: TreeRoot> BindPat<R> {}
 
-// ImplItem
+// BlockExpr
 #[derive(Debug, Clone, Copy)]
-pub struct ImplItem<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct BlockExpr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for ImplItem<R> {
+impl<R: TreeRoot> AstNode<R> for BlockExpr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            IMPL_ITEM => Some(ImplItem { syntax }),
+            BLOCK_EXPR => Some(BlockExpr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ImplItem<R> {}
+impl<R: TreeRoot> BlockExpr<R> {
+    pub fn statements<'a>(&'a self) -> impl Iterator<Item = Stmt<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(Stmt::cast)
+    }
 
-// ImplTraitType
-#[derive(Debug, Clone, Copy)]
-pub struct ImplTraitType<R: TreeRoot = Arc<SyntaxRoot>> {
-    syntax: SyntaxNode<R>,
-}
+    pub fn label(&self) -> Option<Label<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Label::cast)
+            .next()
+    }
 
-impl<R: TreeRoot> AstNode<R> for ImplTraitType<R> {
-    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
-        match syntax.kind() {
-            IMPL_TRAIT_TYPE => Some(ImplTraitType { syntax }),
-            _ => None,
-        }
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
     }
-    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ImplTraitType<R> {}
-
-// Module
+// BoxExpr
 #[derive(Debug, Clone, Copy)]
-pub struct Module<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct BoxExpr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for Module<R> {
+impl<R: TreeRoot> AstNode<R> for BoxExpr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            MODULE => Some(Module { syntax }),
+            BOX_EXPR => Some(BoxExpr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ast::NameOwner<R> for Module<R> {}
-impl<R: TreeRoot> ast::AttrsOwner<R> for Module<R> {}
-impl<R: TreeRoot> Module<R> {}
+impl<R: TreeRoot> BoxExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
 
-// Name
+// BreakExpr
 #[derive(Debug, Clone, Copy)]
-pub struct Name<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct BreakExpr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for Name<R> {
+impl<R: TreeRoot> AstNode<R> for BreakExpr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            NAME => Some(Name { syntax }),
+            BREAK_EXPR => Some(BreakExpr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> Name<R> {}
+impl<R: TreeRoot> BreakExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
 
-// NameRef
+// CallExpr
 #[derive(Debug, Clone, Copy)]
-pub struct NameRef<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct CallExpr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for NameRef<R> {
+impl<R: TreeRoot> AstNode<R> for CallExpr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            NAME_REF => Some(NameRef { syntax }),
+            CALL_EXPR => Some(CallExpr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> NameRef<R> {}
+impl<R: TreeRoot> CallExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
 
-// NamedField
+    pub fn arg_list(&self) -> Option<ArgList<R>> {
+        self.syntax()
+            .children()
+            .filter_map(ArgList::cast)
+            .next()
+    }
+}
+
+// CastExpr
 #[derive(Debug, Clone, Copy)]
-pub struct NamedField<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct CastExpr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for NamedField<R> {
+impl<R: TreeRoot> AstNode<R> for CastExpr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            NAMED_FIELD => Some(NamedField { syntax }),
+            CAST_EXPR => Some(CastExpr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ast::NameOwner<R> for NamedField<R> {}
-impl<R: TreeRoot> ast::AttrsOwner<R> for NamedField<R> {}
-impl<R: TreeRoot> NamedField<R> {}
+impl<R: TreeRoot> CastExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
 
-// NeverType
+    pub fn type_ref(&self) -> Option<TypeRef<R>> {
+        self.syntax()
+            .children()
+            .filter_map(TypeRef::cast)
+            .next()
+    }
+}
+
+// Condition
 #[derive(Debug, Clone, Copy)]
-pub struct NeverType<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct Condition<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for NeverType<R> {
+impl<R: TreeRoot> AstNode<R> for Condition<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            NEVER_TYPE => Some(NeverType { syntax }),
+            CONDITION => Some(Condition { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> NeverType<R> {}
+impl<R: TreeRoot> Condition<R> {
+    pub fn pat(&self) -> Option<Pat<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Pat::cast)
+            .next()
+    }
 
-// NominalDef
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// ConstDef
 #[derive(Debug, Clone, Copy)]
-pub enum NominalDef<R: TreeRoot = Arc<SyntaxRoot>> {
-    StructDef(StructDef<R>),
-    EnumDef(EnumDef<R>),
+pub struct ConstDef<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for NominalDef<R> {
+impl<R: TreeRoot> AstNode<R> for ConstDef<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            STRUCT_DEF => Some(NominalDef::StructDef(StructDef { syntax })),
-            ENUM_DEF => Some(NominalDef::EnumDef(EnumDef { syntax })),
+            CONST_DEF => Some(ConstDef { syntax }),
             _ => None,
         }
     }
-    fn syntax(&self) -> &SyntaxNode<R> {
-        match self {
-            NominalDef::StructDef(inner) => inner.syntax(),
-            NominalDef::EnumDef(inner) => inner.syntax(),
-        }
-    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ast::AttrsOwner<R> for NominalDef<R> {}
-impl<R: TreeRoot> NominalDef<R> {}
+impl<R: TreeRoot> ast::NameOwner<R> for ConstDef<R> {}
+impl<R: TreeRoot> ast::AttrsOwner<R> for ConstDef<R> {}
+impl<R: TreeRoot> ConstDef<R> {}
 
-// ParenType
+// ContinueExpr
 #[derive(Debug, Clone, Copy)]
-pub struct ParenType<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct ContinueExpr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for ParenType<R> {
+impl<R: TreeRoot> AstNode<R> for ContinueExpr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            PAREN_TYPE => Some(ParenType { syntax }),
+            CONTINUE_EXPR => Some(ContinueExpr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ParenType<R> {}
+impl<R: TreeRoot> ContinueExpr<R> {}
 
-// PathType
+// DynTraitType
 #[derive(Debug, Clone, Copy)]
-pub struct PathType<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct DynTraitType<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for PathType<R> {
+impl<R: TreeRoot> AstNode<R> for DynTraitType<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            PATH_TYPE => Some(PathType { syntax }),
+            DYN_TRAIT_TYPE => Some(DynTraitType { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> PathType<R> {}
+impl<R: TreeRoot> DynTraitType<R> {}
 
-// PlaceholderType
+// EnumDef
 #[derive(Debug, Clone, Copy)]
-pub struct PlaceholderType<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct EnumDef<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for PlaceholderType<R> {
+impl<R: TreeRoot> AstNode<R> for EnumDef<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            PLACEHOLDER_TYPE => Some(PlaceholderType { syntax }),
+            ENUM_DEF => Some(EnumDef { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> PlaceholderType<R> {}
+impl<R: TreeRoot> ast::NameOwner<R> for EnumDef<R> {}
+impl<R: TreeRoot> ast::AttrsOwner<R> for EnumDef<R> {}
+impl<R: TreeRoot> EnumDef<R> {}
 
-// PointerType
+// Expr
 #[derive(Debug, Clone, Copy)]
-pub struct PointerType<R: TreeRoot = Arc<SyntaxRoot>> {
-    syntax: SyntaxNode<R>,
+pub enum Expr<R: TreeRoot = Arc<SyntaxRoot>> {
+    TupleExpr(TupleExpr<R>),
+    ArrayExpr(ArrayExpr<R>),
+    ParenExpr(ParenExpr<R>),
+    PathExpr(PathExpr<R>),
+    LambdaExpr(LambdaExpr<R>),
+    IfExpr(IfExpr<R>),
+    LoopExpr(LoopExpr<R>),
+    ForExpr(ForExpr<R>),
+    WhileExpr(WhileExpr<R>),
+    ContinueExpr(ContinueExpr<R>),
+    BreakExpr(BreakExpr<R>),
+    BlockExpr(BlockExpr<R>),
+    ReturnExpr(ReturnExpr<R>),
+    YieldExpr(YieldExpr<R>),
+    AsyncBlockExpr(AsyncBlockExpr<R>),
+    TryBlockExpr(TryBlockExpr<R>),
+    MatchExpr(MatchExpr<R>),
+    StructLit(StructLit<R>),
+    CallExpr(CallExpr<R>),
+    IndexExpr(IndexExpr<R>),
+    MethodCallExpr(MethodCallExpr<R>),
+    FieldExpr(FieldExpr<R>),
+    TryExpr(TryExpr<R>),
+    AwaitExpr(AwaitExpr<R>),
+    CastExpr(CastExpr<R>),
+    RefExpr(RefExpr<R>),
+    PrefixExpr(PrefixExpr<R>),
+    BoxExpr(BoxExpr<R>),
+    RangeExpr(RangeExpr<R>),
+    BinExpr(BinExpr<R>),
+    Literal(Literal<R>),
+    MacroCall(MacroCall<R>),
 }
 
-impl<R: TreeRoot> AstNode<R> for PointerType<R> {
+impl<R: TreeRoot> AstNode<R> for Expr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            POINTER_TYPE => Some(PointerType { syntax }),
+            TUPLE_EXPR => Some(Expr::TupleExpr(TupleExpr { syntax })),
+            ARRAY_EXPR => Some(Expr::ArrayExpr(ArrayExpr { syntax })),
+            PAREN_EXPR => Some(Expr::ParenExpr(ParenExpr { syntax })),
+            PATH_EXPR => Some(Expr::PathExpr(PathExpr { syntax })),
+            LAMBDA_EXPR => Some(Expr::LambdaExpr(LambdaExpr { syntax })),
+            IF_EXPR => Some(Expr::IfExpr(IfExpr { syntax })),
+            LOOP_EXPR => Some(Expr::LoopExpr(LoopExpr { syntax })),
+            FOR_EXPR => Some(Expr::ForExpr(ForExpr { syntax })),
+            WHILE_EXPR => Some(Expr::WhileExpr(WhileExpr { syntax })),
+            CONTINUE_EXPR => Some(Expr::ContinueExpr(ContinueExpr { syntax })),
+            BREAK_EXPR => Some(Expr::BreakExpr(BreakExpr { syntax })),
+            BLOCK_EXPR => Some(Expr::BlockExpr(BlockExpr { syntax })),
+            RETURN_EXPR => Some(Expr::ReturnExpr(ReturnExpr { syntax })),
+            YIELD_EXPR => Some(Expr::YieldExpr(YieldExpr { syntax })),
+            ASYNC_BLOCK_EXPR => Some(Expr::AsyncBlockExpr(AsyncBlockExpr { syntax })),
+            TRY_BLOCK_EXPR => Some(Expr::TryBlockExpr(TryBlockExpr { syntax })),
+            MATCH_EXPR => Some(Expr::MatchExpr(MatchExpr { syntax })),
+            STRUCT_LIT => Some(Expr::StructLit(StructLit { syntax })),
+            CALL_EXPR => Some(Expr::CallExpr(CallExpr { syntax })),
+            INDEX_EXPR => Some(Expr::IndexExpr(IndexExpr { syntax })),
+            METHOD_CALL_EXPR => Some(Expr::MethodCallExpr(MethodCallExpr { syntax })),
+            FIELD_EXPR => Some(Expr::FieldExpr(FieldExpr { syntax })),
+            TRY_EXPR => Some(Expr::TryExpr(TryExpr { syntax })),
+            AWAIT_EXPR => Some(Expr::AwaitExpr(AwaitExpr { syntax })),
+            CAST_EXPR => Some(Expr::CastExpr(CastExpr { syntax })),
+            REF_EXPR => Some(Expr::RefExpr(RefExpr { syntax })),
+            PREFIX_EXPR => Some(Expr::PrefixExpr(PrefixExpr { syntax })),
+            BOX_EXPR => Some(Expr::BoxExpr(BoxExpr { syntax })),
+            RANGE_EXPR => Some(Expr::RangeExpr(RangeExpr { syntax })),
+            BIN_EXPR => Some(Expr::BinExpr(BinExpr { syntax })),
+            LITERAL => Some(Expr::Literal(Literal { syntax })),
+            MACRO_CALL => Some(Expr::MacroCall(MacroCall { syntax })),
             _ => None,
         }
     }
-    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+    fn syntax(&self) -> &SyntaxNode<R> {
+        match self {
+            Expr::TupleExpr(inner) => inner.syntax(),
+            Expr::ArrayExpr(inner) => inner.syntax(),
+            Expr::ParenExpr(inner) => inner.syntax(),
+            Expr::PathExpr(inner) => inner.syntax(),
+            Expr::LambdaExpr(inner) => inner.syntax(),
+            Expr::IfExpr(inner) => inner.syntax(),
+            Expr::LoopExpr(inner) => inner.syntax(),
+            Expr::ForExpr(inner) => inner.syntax(),
+            Expr::WhileExpr(inner) => inner.syntax(),
+            Expr::ContinueExpr(inner) => inner.syntax(),
+            Expr::BreakExpr(inner) => inner.syntax(),
+            Expr::BlockExpr(inner) => inner.syntax(),
+            Expr::ReturnExpr(inner) => inner.syntax(),
+            Expr::YieldExpr(inner) => inner.syntax(),
+            Expr::AsyncBlockExpr(inner) => inner.syntax(),
+            Expr::TryBlockExpr(inner) => inner.syntax(),
+            Expr::MatchExpr(inner) => inner.syntax(),
+            Expr::StructLit(inner) => inner.syntax(),
+            Expr::CallExpr(inner) => inner.syntax(),
+            Expr::IndexExpr(inner) => inner.syntax(),
+            Expr::MethodCallExpr(inner) => inner.syntax(),
+            Expr::FieldExpr(inner) => inner.syntax(),
+            Expr::TryExpr(inner) => inner.syntax(),
+            Expr::AwaitExpr(inner) => inner.syntax(),
+            Expr::CastExpr(inner) => inner.syntax(),
+            Expr::RefExpr(inner) => inner.syntax(),
+            Expr::PrefixExpr(inner) => inner.syntax(),
+            Expr::BoxExpr(inner) => inner.syntax(),
+            Expr::RangeExpr(inner) => inner.syntax(),
+            Expr::BinExpr(inner) => inner.syntax(),
+            Expr::Literal(inner) => inner.syntax(),
+            Expr::MacroCall(inner) => inner.syntax(),
+        }
+    }
 }
 
-impl<R: TreeRoot> PointerType<R> {}
+impl<R: TreeRoot> Expr<R> {}
 
-// ReferenceType
+// ExprStmt
 #[derive(Debug, Clone, Copy)]
-pub struct ReferenceType<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct ExprStmt<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for ReferenceType<R> {
+impl<R: TreeRoot> AstNode<R> for ExprStmt<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            REFERENCE_TYPE => Some(ReferenceType { syntax }),
+            EXPR_STMT => Some(ExprStmt { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ReferenceType<R> {}
+impl<R: TreeRoot> ExprStmt<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
 
-// SliceType
+// ExternCrateItem
 #[derive(Debug, Clone, Copy)]
-pub struct SliceType<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct ExternCrateItem<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for SliceType<R> {
+impl<R: TreeRoot> AstNode<R> for ExternCrateItem<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            SLICE_TYPE => Some(SliceType { syntax }),
+            EXTERN_CRATE_ITEM => Some(ExternCrateItem { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> SliceType<R> {}
+impl<R: TreeRoot> ExternCrateItem<R> {}
 
-// StaticDef
+// FieldExpr
 #[derive(Debug, Clone, Copy)]
-pub struct StaticDef<R: TreeRoot = Arc<SyntaxRoot>> {
+pub struct FieldExpr<R: TreeRoot = Arc<SyntaxRoot>> {
     syntax: SyntaxNode<R>,
 }
 
-impl<R: TreeRoot> AstNode<R> for StaticDef<R> {
+impl<R: TreeRoot> AstNode<R> for FieldExpr<R> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
         match syntax.kind() {
-            STATIC_DEF => Some(StaticDef { syntax }),
+            FIELD_EXPR => Some(FieldExpr { syntax }),
             _ => None,
         }
     }
     fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
 }
 
-impl<R: TreeRoot> ast::NameOwner<R> for StaticDef<R> {}
+impl<R: TreeRoot> FieldExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+
+    pub fn name_ref(&self) -> Option<NameRef<R>> {
+        self.syntax()
+            .children()
+            .filter_map(NameRef::cast)
+            .next()
+    }
+}
+
+// File
+#[derive(Debug, Clone, Copy)]
+pub struct File<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for File<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            FILE => Some(File { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> File<R> {
+    pub fn functions<'a>(&'a self) -> impl Iterator<Item = FnDef<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(FnDef::cast)
+    }
+
+    pub fn items<'a>(&'a self) -> impl Iterator<Item = ModuleItem<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(ModuleItem::cast)
+    }
+}
+
+// FnDef
+#[derive(Debug, Clone, Copy)]
+pub struct FnDef<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for FnDef<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            FN_DEF => Some(FnDef { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ast::NameOwner<R> for FnDef<R> {}
+impl<R: TreeRoot> ast::AttrsOwner<R> for FnDef<R> {}
+impl<R: TreeRoot> FnDef<R> {
+    pub fn param_list(&self) -> Option<ParamList<R>> {
+        self.syntax()
+            .children()
+            .filter_map(ParamList::cast)
+            .next()
+    }
+
+    pub fn body(&self) -> Option<BlockExpr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(BlockExpr::cast)
+            .next()
+    }
+}
+
+// FnPointerType
+#[derive(Debug, Clone, Copy)]
+pub struct FnPointerType<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for FnPointerType<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            FN_POINTER_TYPE => Some(FnPointerType { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> FnPointerType<R> {}
+
+// ForExpr
+#[derive(Debug, Clone, Copy)]
+pub struct ForExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for ForExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            FOR_EXPR => Some(ForExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ForExpr<R> {
+    pub fn label(&self) -> Option<Label<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Label::cast)
+            .next()
+    }
+
+    pub fn pat(&self) -> Option<Pat<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Pat::cast)
+            .next()
+    }
+
+    pub fn iterable(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+
+    pub fn body(&self) -> Option<BlockExpr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(BlockExpr::cast)
+            .next()
+    }
+}
+
+// ForType
+#[derive(Debug, Clone, Copy)]
+pub struct ForType<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for ForType<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            FOR_TYPE => Some(ForType { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ForType<R> {}
+
+// IfExpr
+#[derive(Debug, Clone, Copy)]
+pub struct IfExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for IfExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            IF_EXPR => Some(IfExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> IfExpr<R> {
+    pub fn condition(&self) -> Option<Condition<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Condition::cast)
+            .next()
+    }
+
+    pub fn then_branch(&self) -> Option<BlockExpr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(BlockExpr::cast)
+            .next()
+    }
+}
+
+// ImplItem
+#[derive(Debug, Clone, Copy)]
+pub struct ImplItem<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for ImplItem<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            IMPL_ITEM => Some(ImplItem { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ImplItem<R> {
+    pub fn item_list(&self) -> Option<ItemList<R>> {
+        self.syntax()
+            .children()
+            .filter_map(ItemList::cast)
+            .next()
+    }
+}
+
+// ImplMember
+#[derive(Debug, Clone, Copy)]
+pub enum ImplMember<R: TreeRoot = Arc<SyntaxRoot>> {
+    FnDef(FnDef<R>),
+    TypeDef(TypeDef<R>),
+    ConstDef(ConstDef<R>),
+}
+
+impl<R: TreeRoot> AstNode<R> for ImplMember<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            FN_DEF => Some(ImplMember::FnDef(FnDef { syntax })),
+            TYPE_DEF => Some(ImplMember::TypeDef(TypeDef { syntax })),
+            CONST_DEF => Some(ImplMember::ConstDef(ConstDef { syntax })),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> {
+        match self {
+            ImplMember::FnDef(inner) => inner.syntax(),
+            ImplMember::TypeDef(inner) => inner.syntax(),
+            ImplMember::ConstDef(inner) => inner.syntax(),
+        }
+    }
+}
+
+impl<R: TreeRoot> ImplMember<R> {}
+
+// ImplTraitType
+#[derive(Debug, Clone, Copy)]
+pub struct ImplTraitType<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for ImplTraitType<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            IMPL_TRAIT_TYPE => Some(ImplTraitType { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ImplTraitType<R> {}
+
+// IndexExpr
+#[derive(Debug, Clone, Copy)]
+pub struct IndexExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for IndexExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            INDEX_EXPR => Some(IndexExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> IndexExpr<R> {}
+
+// ItemList
+#[derive(Debug, Clone, Copy)]
+pub struct ItemList<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for ItemList<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            ITEM_LIST => Some(ItemList { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ItemList<R> {
+    pub fn items<'a>(&'a self) -> impl Iterator<Item = ModuleItem<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(ModuleItem::cast)
+    }
+
+    pub fn impl_members<'a>(&'a self) -> impl Iterator<Item = ImplMember<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(ImplMember::cast)
+    }
+}
+
+// Label
+#[derive(Debug, Clone, Copy)]
+pub struct Label<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for Label<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            LABEL => Some(Label { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> Label<R> {}
+
+// LambdaExpr
+#[derive(Debug, Clone, Copy)]
+pub struct LambdaExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for LambdaExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            LAMBDA_EXPR => Some(LambdaExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> LambdaExpr<R> {
+    pub fn param_list(&self) -> Option<ParamList<R>> {
+        self.syntax()
+            .children()
+            .filter_map(ParamList::cast)
+            .next()
+    }
+
+    pub fn body(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// LetStmt
+#[derive(Debug, Clone, Copy)]
+pub struct LetStmt<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for LetStmt<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            LET_STMT => Some(LetStmt { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> LetStmt<R> {
+    pub fn pat(&self) -> Option<Pat<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Pat::cast)
+            .next()
+    }
+
+    pub fn type_ref(&self) -> Option<TypeRef<R>> {
+        self.syntax()
+            .children()
+            .filter_map(TypeRef::cast)
+            .next()
+    }
+
+    pub fn initializer(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// Literal
+#[derive(Debug, Clone, Copy)]
+pub struct Literal<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for Literal<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            LITERAL => Some(Literal { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> Literal<R> {}
+
+// LiteralPat
+#[derive(Debug, Clone, Copy)]
+pub struct LiteralPat<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for LiteralPat<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            LITERAL_PAT => Some(LiteralPat { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> LiteralPat<R> {
+    pub fn literal(&self) -> Option<Literal<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Literal::cast)
+            .next()
+    }
+}
+
+// LoopExpr
+#[derive(Debug, Clone, Copy)]
+pub struct LoopExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for LoopExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            LOOP_EXPR => Some(LoopExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> LoopExpr<R> {
+    pub fn label(&self) -> Option<Label<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Label::cast)
+            .next()
+    }
+
+    pub fn body(&self) -> Option<BlockExpr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(BlockExpr::cast)
+            .next()
+    }
+}
+
+// MacroCall
+#[derive(Debug, Clone, Copy)]
+pub struct MacroCall<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for MacroCall<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            MACRO_CALL => Some(MacroCall { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> MacroCall<R> {
+    pub fn path(&self) -> Option<Path<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Path::cast)
+            .next()
+    }
+
+    pub fn token_tree(&self) -> Option<TokenTree<R>> {
+        self.syntax()
+            .children()
+            .filter_map(TokenTree::cast)
+            .next()
+    }
+}
+
+// MacroRule
+#[derive(Debug, Clone, Copy)]
+pub struct MacroRule<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for MacroRule<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            MACRO_RULE => Some(MacroRule { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> MacroRule<R> {}
+
+// MacroRules
+#[derive(Debug, Clone, Copy)]
+pub struct MacroRules<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for MacroRules<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            MACRO_RULES => Some(MacroRules { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ast::NameOwner<R> for MacroRules<R> {}
+impl<R: TreeRoot> ast::AttrsOwner<R> for MacroRules<R> {}
+impl<R: TreeRoot> MacroRules<R> {
+    pub fn rules<'a>(&'a self) -> impl Iterator<Item = MacroRule<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(MacroRule::cast)
+    }
+}
+
+// MatchArm
+#[derive(Debug, Clone, Copy)]
+pub struct MatchArm<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for MatchArm<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            MATCH_ARM => Some(MatchArm { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> MatchArm<R> {
+    pub fn pats<'a>(&'a self) -> impl Iterator<Item = Pat<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(Pat::cast)
+    }
+
+    pub fn guard(&self) -> Option<MatchGuard<R>> {
+        self.syntax()
+            .children()
+            .filter_map(MatchGuard::cast)
+            .next()
+    }
+
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// MatchExpr
+#[derive(Debug, Clone, Copy)]
+pub struct MatchExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for MatchExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            MATCH_EXPR => Some(MatchExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> MatchExpr<R> {
+    pub fn arms<'a>(&'a self) -> impl Iterator<Item = MatchArm<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(MatchArm::cast)
+    }
+
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// MatchGuard
+#[derive(Debug, Clone, Copy)]
+pub struct MatchGuard<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for MatchGuard<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            MATCH_GUARD => Some(MatchGuard { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> MatchGuard<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// MethodCallExpr
+#[derive(Debug, Clone, Copy)]
+pub struct MethodCallExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for MethodCallExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            METHOD_CALL_EXPR => Some(MethodCallExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> MethodCallExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+
+    pub fn name_ref(&self) -> Option<NameRef<R>> {
+        self.syntax()
+            .children()
+            .filter_map(NameRef::cast)
+            .next()
+    }
+
+    pub fn arg_list(&self) -> Option<ArgList<R>> {
+        self.syntax()
+            .children()
+            .filter_map(ArgList::cast)
+            .next()
+    }
+}
+
+// Module
+#[derive(Debug, Clone, Copy)]
+pub struct Module<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for Module<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            MODULE => Some(Module { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ast::NameOwner<R> for Module<R> {}
+impl<R: TreeRoot> ast::AttrsOwner<R> for Module<R> {}
+impl<R: TreeRoot> Module<R> {
+    pub fn item_list(&self) -> Option<ItemList<R>> {
+        self.syntax()
+            .children()
+            .filter_map(ItemList::cast)
+            .next()
+    }
+}
+
+// ModuleItem
+#[derive(Debug, Clone, Copy)]
+pub enum ModuleItem<R: TreeRoot = Arc<SyntaxRoot>> {
+    StructDef(StructDef<R>),
+    UnionDef(UnionDef<R>),
+    EnumDef(EnumDef<R>),
+    FnDef(FnDef<R>),
+    TraitDef(TraitDef<R>),
+    TypeDef(TypeDef<R>),
+    ImplItem(ImplItem<R>),
+    UseItem(UseItem<R>),
+    ExternCrateItem(ExternCrateItem<R>),
+    ConstDef(ConstDef<R>),
+    StaticDef(StaticDef<R>),
+    Module(Module<R>),
+    MacroRules(MacroRules<R>),
+    MacroCall(MacroCall<R>),
+}
+
+impl<R: TreeRoot> AstNode<R> for ModuleItem<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            STRUCT_DEF => Some(ModuleItem::StructDef(StructDef { syntax })),
+            UNION_DEF => Some(ModuleItem::UnionDef(UnionDef { syntax })),
+            ENUM_DEF => Some(ModuleItem::EnumDef(EnumDef { syntax })),
+            FN_DEF => Some(ModuleItem::FnDef(FnDef { syntax })),
+            TRAIT_DEF => Some(ModuleItem::TraitDef(TraitDef { syntax })),
+            TYPE_DEF => Some(ModuleItem::TypeDef(TypeDef { syntax })),
+            IMPL_ITEM => Some(ModuleItem::ImplItem(ImplItem { syntax })),
+            USE_ITEM => Some(ModuleItem::UseItem(UseItem { syntax })),
+            EXTERN_CRATE_ITEM => Some(ModuleItem::ExternCrateItem(ExternCrateItem { syntax })),
+            CONST_DEF => Some(ModuleItem::ConstDef(ConstDef { syntax })),
+            STATIC_DEF => Some(ModuleItem::StaticDef(StaticDef { syntax })),
+            MODULE => Some(ModuleItem::Module(Module { syntax })),
+            MACRO_RULES => Some(ModuleItem::MacroRules(MacroRules { syntax })),
+            MACRO_CALL => Some(ModuleItem::MacroCall(MacroCall { syntax })),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> {
+        match self {
+            ModuleItem::StructDef(inner) => inner.syntax(),
+            ModuleItem::UnionDef(inner) => inner.syntax(),
+            ModuleItem::EnumDef(inner) => inner.syntax(),
+            ModuleItem::FnDef(inner) => inner.syntax(),
+            ModuleItem::TraitDef(inner) => inner.syntax(),
+            ModuleItem::TypeDef(inner) => inner.syntax(),
+            ModuleItem::ImplItem(inner) => inner.syntax(),
+            ModuleItem::UseItem(inner) => inner.syntax(),
+            ModuleItem::ExternCrateItem(inner) => inner.syntax(),
+            ModuleItem::ConstDef(inner) => inner.syntax(),
+            ModuleItem::StaticDef(inner) => inner.syntax(),
+            ModuleItem::Module(inner) => inner.syntax(),
+            ModuleItem::MacroRules(inner) => inner.syntax(),
+            ModuleItem::MacroCall(inner) => inner.syntax(),
+        }
+    }
+}
+
+impl<R: TreeRoot> ModuleItem<R> {}
+
+// Name
+#[derive(Debug, Clone, Copy)]
+pub struct Name<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for Name<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            NAME => Some(Name { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> Name<R> {}
+
+// NameRef
+#[derive(Debug, Clone, Copy)]
+pub struct NameRef<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for NameRef<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            NAME_REF => Some(NameRef { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> NameRef<R> {}
+
+// NamedField
+#[derive(Debug, Clone, Copy)]
+pub struct NamedField<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for NamedField<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            NAMED_FIELD => Some(NamedField { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ast::NameOwner<R> for NamedField<R> {}
+impl<R: TreeRoot> ast::AttrsOwner<R> for NamedField<R> {}
+impl<R: TreeRoot> NamedField<R> {}
+
+// NeverType
+#[derive(Debug, Clone, Copy)]
+pub struct NeverType<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for NeverType<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            NEVER_TYPE => Some(NeverType { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> NeverType<R> {}
+
+// NominalDef
+#[derive(Debug, Clone, Copy)]
+pub enum NominalDef<R: TreeRoot = Arc<SyntaxRoot>> {
+    StructDef(StructDef<R>),
+    UnionDef(UnionDef<R>),
+    EnumDef(EnumDef<R>),
+}
+
+impl<R: TreeRoot> AstNode<R> for NominalDef<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            STRUCT_DEF => Some(NominalDef::StructDef(StructDef { syntax })),
+            UNION_DEF => Some(NominalDef::UnionDef(UnionDef { syntax })),
+            ENUM_DEF => Some(NominalDef::EnumDef(EnumDef { syntax })),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> {
+        match self {
+            NominalDef::StructDef(inner) => inner.syntax(),
+            NominalDef::UnionDef(inner) => inner.syntax(),
+            NominalDef::EnumDef(inner) => inner.syntax(),
+        }
+    }
+}
+
+impl<R: TreeRoot> ast::AttrsOwner<R> for NominalDef<R> {}
+impl<R: TreeRoot> NominalDef<R> {}
+
+// Param
+#[derive(Debug, Clone, Copy)]
+pub struct Param<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for Param<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PARAM => Some(Param { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> Param<R> {
+    pub fn pat(&self) -> Option<Pat<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Pat::cast)
+            .next()
+    }
+
+    pub fn type_ref(&self) -> Option<TypeRef<R>> {
+        self.syntax()
+            .children()
+            .filter_map(TypeRef::cast)
+            .next()
+    }
+}
+
+// ParamList
+#[derive(Debug, Clone, Copy)]
+pub struct ParamList<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for ParamList<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PARAM_LIST => Some(ParamList { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ParamList<R> {
+    pub fn params<'a>(&'a self) -> impl Iterator<Item = Param<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(Param::cast)
+    }
+
+    pub fn self_param(&self) -> Option<SelfParam<R>> {
+        self.syntax()
+            .children()
+            .filter_map(SelfParam::cast)
+            .next()
+    }
+}
+
+// ParenExpr
+#[derive(Debug, Clone, Copy)]
+pub struct ParenExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for ParenExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PAREN_EXPR => Some(ParenExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ParenExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// ParenType
+#[derive(Debug, Clone, Copy)]
+pub struct ParenType<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for ParenType<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PAREN_TYPE => Some(ParenType { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ParenType<R> {}
+
+// Pat
+#[derive(Debug, Clone, Copy)]
+pub enum Pat<R: TreeRoot = Arc<SyntaxRoot>> {
+    RefPat(RefPat<R>),
+    BindPat(BindPat<R>),
+    PlaceholderPat(PlaceholderPat<R>),
+    PathPat(PathPat<R>),
+    StructPat(StructPat<R>),
+    TupleStructPat(TupleStructPat<R>),
+    TuplePat(TuplePat<R>),
+    SlicePat(SlicePat<R>),
+    RangePat(RangePat<R>),
+    LiteralPat(LiteralPat<R>),
+}
+
+impl<R: TreeRoot> AstNode<R> for Pat<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            REF_PAT => Some(Pat::RefPat(RefPat { syntax })),
+            BIND_PAT => Some(Pat::BindPat(BindPat { syntax })),
+            PLACEHOLDER_PAT => Some(Pat::PlaceholderPat(PlaceholderPat { syntax })),
+            PATH_PAT => Some(Pat::PathPat(PathPat { syntax })),
+            STRUCT_PAT => Some(Pat::StructPat(StructPat { syntax })),
+            TUPLE_STRUCT_PAT => Some(Pat::TupleStructPat(TupleStructPat { syntax })),
+            TUPLE_PAT => Some(Pat::TuplePat(TuplePat { syntax })),
+            SLICE_PAT => Some(Pat::SlicePat(SlicePat { syntax })),
+            RANGE_PAT => Some(Pat::RangePat(RangePat { syntax })),
+            LITERAL_PAT => Some(Pat::LiteralPat(LiteralPat { syntax })),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> {
+        match self {
+            Pat::RefPat(inner) => inner.syntax(),
+            Pat::BindPat(inner) => inner.syntax(),
+            Pat::PlaceholderPat(inner) => inner.syntax(),
+            Pat::PathPat(inner) => inner.syntax(),
+            Pat::StructPat(inner) => inner.syntax(),
+            Pat::TupleStructPat(inner) => inner.syntax(),
+            Pat::TuplePat(inner) => inner.syntax(),
+            Pat::SlicePat(inner) => inner.syntax(),
+            Pat::RangePat(inner) => inner.syntax(),
+            Pat::LiteralPat(inner) => inner.syntax(),
+        }
+    }
+}
+
+impl<R: TreeRoot> Pat<R> {}
+
+// Path
+#[derive(Debug, Clone, Copy)]
+pub struct Path<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for Path<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PATH => Some(Path { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> Path<R> {
+    pub fn qualifier(&self) -> Option<Path<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Path::cast)
+            .next()
+    }
+
+    pub fn segment(&self) -> Option<PathSegment<R>> {
+        self.syntax()
+            .children()
+            .filter_map(PathSegment::cast)
+            .next()
+    }
+}
+
+// PathExpr
+#[derive(Debug, Clone, Copy)]
+pub struct PathExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for PathExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PATH_EXPR => Some(PathExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> PathExpr<R> {
+    pub fn path(&self) -> Option<Path<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Path::cast)
+            .next()
+    }
+}
+
+// PathPat
+#[derive(Debug, Clone, Copy)]
+pub struct PathPat<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for PathPat<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PATH_PAT => Some(PathPat { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> PathPat<R> {
+    pub fn path(&self) -> Option<Path<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Path::cast)
+            .next()
+    }
+}
+
+// PathSegment
+#[derive(Debug, Clone, Copy)]
+pub struct PathSegment<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for PathSegment<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PATH_SEGMENT => Some(PathSegment { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> PathSegment<R> {
+    pub fn name_ref(&self) -> Option<NameRef<R>> {
+        self.syntax()
+            .children()
+            .filter_map(NameRef::cast)
+            .next()
+    }
+}
+
+// PathType
+#[derive(Debug, Clone, Copy)]
+pub struct PathType<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for PathType<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PATH_TYPE => Some(PathType { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> PathType<R> {}
+
+// PlaceholderPat
+#[derive(Debug, Clone, Copy)]
+pub struct PlaceholderPat<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for PlaceholderPat<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PLACEHOLDER_PAT => Some(PlaceholderPat { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> PlaceholderPat<R> {}
+
+// PlaceholderType
+#[derive(Debug, Clone, Copy)]
+pub struct PlaceholderType<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for PlaceholderType<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PLACEHOLDER_TYPE => Some(PlaceholderType { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> PlaceholderType<R> {}
+
+// PointerType
+#[derive(Debug, Clone, Copy)]
+pub struct PointerType<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for PointerType<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            POINTER_TYPE => Some(PointerType { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> PointerType<R> {}
+
+// PrefixExpr
+#[derive(Debug, Clone, Copy)]
+pub struct PrefixExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for PrefixExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            PREFIX_EXPR => Some(PrefixExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> PrefixExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// RangeExpr
+#[derive(Debug, Clone, Copy)]
+pub struct RangeExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for RangeExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            RANGE_EXPR => Some(RangeExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> RangeExpr<R> {}
+
+// RangePat
+#[derive(Debug, Clone, Copy)]
+pub struct RangePat<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for RangePat<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            RANGE_PAT => Some(RangePat { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> RangePat<R> {}
+
+// RefExpr
+#[derive(Debug, Clone, Copy)]
+pub struct RefExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for RefExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            REF_EXPR => Some(RefExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> RefExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// RefPat
+#[derive(Debug, Clone, Copy)]
+pub struct RefPat<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for RefPat<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            REF_PAT => Some(RefPat { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> RefPat<R> {
+    pub fn pat(&self) -> Option<Pat<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Pat::cast)
+            .next()
+    }
+}
+
+// ReferenceType
+#[derive(Debug, Clone, Copy)]
+pub struct ReferenceType<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for ReferenceType<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            REFERENCE_TYPE => Some(ReferenceType { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ReferenceType<R> {}
+
+// ReturnExpr
+#[derive(Debug, Clone, Copy)]
+pub struct ReturnExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for ReturnExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            RETURN_EXPR => Some(ReturnExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ReturnExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// SelfParam
+#[derive(Debug, Clone, Copy)]
+pub struct SelfParam<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for SelfParam<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            SELF_PARAM => Some(SelfParam { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> SelfParam<R> {}
+
+// SlicePat
+#[derive(Debug, Clone, Copy)]
+pub struct SlicePat<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for SlicePat<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            SLICE_PAT => Some(SlicePat { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> SlicePat<R> {}
+
+// SliceType
+#[derive(Debug, Clone, Copy)]
+pub struct SliceType<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for SliceType<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            SLICE_TYPE => Some(SliceType { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> SliceType<R> {}
+
+// StaticDef
+#[derive(Debug, Clone, Copy)]
+pub struct StaticDef<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for StaticDef<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            STATIC_DEF => Some(StaticDef { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ast::NameOwner<R> for StaticDef<R> {}
 impl<R: TreeRoot> ast::AttrsOwner<R> for StaticDef<R> {}
 impl<R: TreeRoot> StaticDef<R> {}
 
+// Stmt
+#[derive(Debug, Clone, Copy)]
+pub enum Stmt<R: TreeRoot = Arc<SyntaxRoot>> {
+    ExprStmt(ExprStmt<R>),
+    LetStmt(LetStmt<R>),
+}
+
+impl<R: TreeRoot> AstNode<R> for Stmt<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            EXPR_STMT => Some(Stmt::ExprStmt(ExprStmt { syntax })),
+            LET_STMT => Some(Stmt::LetStmt(LetStmt { syntax })),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> {
+        match self {
+            Stmt::ExprStmt(inner) => inner.syntax(),
+            Stmt::LetStmt(inner) => inner.syntax(),
+        }
+    }
+}
+
+impl<R: TreeRoot> Stmt<R> {}
+
 // StructDef
 #[derive(Debug, Clone, Copy)]
 pub struct StructDef<R: TreeRoot = Arc<SyntaxRoot>> {
@@ -496,6 +2120,87 @@ impl<R: TreeRoot> StructDef<R> {
     }
 }
 
+// StructLit
+#[derive(Debug, Clone, Copy)]
+pub struct StructLit<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for StructLit<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            STRUCT_LIT => Some(StructLit { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> StructLit<R> {
+    pub fn fields<'a>(&'a self) -> impl Iterator<Item = StructLitField<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(StructLitField::cast)
+    }
+
+    pub fn path(&self) -> Option<Path<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Path::cast)
+            .next()
+    }
+}
+
+// StructLitField
+#[derive(Debug, Clone, Copy)]
+pub struct StructLitField<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for StructLitField<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            STRUCT_LIT_FIELD => Some(StructLitField { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> StructLitField<R> {
+    pub fn name_ref(&self) -> Option<NameRef<R>> {
+        self.syntax()
+            .children()
+            .filter_map(NameRef::cast)
+            .next()
+    }
+
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// StructPat
+#[derive(Debug, Clone, Copy)]
+pub struct StructPat<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for StructPat<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            STRUCT_PAT => Some(StructPat { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> StructPat<R> {}
+
 // TokenTree
 #[derive(Debug, Clone, Copy)]
 pub struct TokenTree<R: TreeRoot = Arc<SyntaxRoot>> {
@@ -532,7 +2237,118 @@ impl<R: TreeRoot> AstNode<R> for TraitDef<R> {
 
 impl<R: TreeRoot> ast::NameOwner<R> for TraitDef<R> {}
 impl<R: TreeRoot> ast::AttrsOwner<R> for TraitDef<R> {}
-impl<R: TreeRoot> TraitDef<R> {}
+impl<R: TreeRoot> TraitDef<R> {
+    pub fn item_list(&self) -> Option<ItemList<R>> {
+        self.syntax()
+            .children()
+            .filter_map(ItemList::cast)
+            .next()
+    }
+}
+
+// TryBlockExpr
+#[derive(Debug, Clone, Copy)]
+pub struct TryBlockExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for TryBlockExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            TRY_BLOCK_EXPR => Some(TryBlockExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> TryBlockExpr<R> {
+    pub fn block(&self) -> Option<BlockExpr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(BlockExpr::cast)
+            .next()
+    }
+}
+
+// TryExpr
+#[derive(Debug, Clone, Copy)]
+pub struct TryExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for TryExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            TRY_EXPR => Some(TryExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> TryExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+// TupleExpr
+#[derive(Debug, Clone, Copy)]
+pub struct TupleExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for TupleExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            TUPLE_EXPR => Some(TupleExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> TupleExpr<R> {}
+
+// TuplePat
+#[derive(Debug, Clone, Copy)]
+pub struct TuplePat<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for TuplePat<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            TUPLE_PAT => Some(TuplePat { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> TuplePat<R> {}
+
+// TupleStructPat
+#[derive(Debug, Clone, Copy)]
+pub struct TupleStructPat<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for TupleStructPat<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            TUPLE_STRUCT_PAT => Some(TupleStructPat { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> TupleStructPat<R> {}
 
 // TupleType
 #[derive(Debug, Clone, Copy)]
@@ -630,3 +2446,156 @@ impl<R: TreeRoot> AstNode<R> for TypeRef<R> {
 
 impl<R: TreeRoot> TypeRef<R> {}
 
+// UnionDef
+#[derive(Debug, Clone, Copy)]
+pub struct UnionDef<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for UnionDef<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            UNION_DEF => Some(UnionDef { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> ast::NameOwner<R> for UnionDef<R> {}
+impl<R: TreeRoot> ast::AttrsOwner<R> for UnionDef<R> {}
+impl<R: TreeRoot> UnionDef<R> {
+    pub fn fields<'a>(&'a self) -> impl Iterator<Item = NamedField<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(NamedField::cast)
+    }
+}
+
+// UseItem
+#[derive(Debug, Clone, Copy)]
+pub struct UseItem<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for UseItem<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            USE_ITEM => Some(UseItem { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> UseItem<R> {
+    pub fn use_tree(&self) -> Option<UseTree<R>> {
+        self.syntax()
+            .children()
+            .filter_map(UseTree::cast)
+            .next()
+    }
+}
+
+// UseTree
+#[derive(Debug, Clone, Copy)]
+pub struct UseTree<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for UseTree<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            USE_TREE => Some(UseTree { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> UseTree<R> {
+    pub fn use_trees<'a>(&'a self) -> impl Iterator<Item = UseTree<R>> + 'a {
+        self.syntax()
+            .children()
+            .filter_map(UseTree::cast)
+    }
+
+    pub fn path(&self) -> Option<Path<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Path::cast)
+            .next()
+    }
+
+    pub fn alias(&self) -> Option<Alias<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Alias::cast)
+            .next()
+    }
+}
+
+// WhileExpr
+#[derive(Debug, Clone, Copy)]
+pub struct WhileExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for WhileExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            WHILE_EXPR => Some(WhileExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> WhileExpr<R> {
+    pub fn label(&self) -> Option<Label<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Label::cast)
+            .next()
+    }
+
+    pub fn condition(&self) -> Option<Condition<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Condition::cast)
+            .next()
+    }
+
+    pub fn body(&self) -> Option<BlockExpr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(BlockExpr::cast)
+            .next()
+    }
+}
+
+// YieldExpr
+#[derive(Debug, Clone, Copy)]
+pub struct YieldExpr<R: TreeRoot = Arc<SyntaxRoot>> {
+    syntax: SyntaxNode<R>,
+}
+
+impl<R: TreeRoot> AstNode<R> for YieldExpr<R> {
+    fn cast(syntax: SyntaxNode<R>) -> Option<Self> {
+        match syntax.kind() {
+            YIELD_EXPR => Some(YieldExpr { syntax }),
+            _ => None,
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode<R> { &self.syntax }
+}
+
+impl<R: TreeRoot> YieldExpr<R> {
+    pub fn expr(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children()
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
@@ -0,0 +1,175 @@
+//! Constructors for well-formed AST nodes.
+//!
+//! Each node is built by parsing a small source template and extracting
+//! the first node of the requested type. The result is detached: it is
+//! the root of its own tree, so its range starts at zero. Use `green()`
+//! together with the tree editing methods to splice it into a file.
+//!
+//! The constructors panic if their arguments don't form a valid node,
+//! for example if a text argument is not a valid name or path.
+use std::sync::Arc;
+
+use itertools::Itertools;
+
+use {
+    ast::{self, AstNode},
+    SyntaxNode, SyntaxRoot,
+};
+
+pub fn name(text: &str) -> ast::Name {
+    ast_from_text(&format!("fn {}() {{}}", text))
+}
+
+pub fn name_ref(text: &str) -> ast::NameRef {
+    ast_from_text(&format!("fn f() {{ {}; }}", text))
+}
+
+/// A path in expression position, like `foo::bar::Baz`, `Vec::<u8>::new`
+/// or `<T as Trait>::f`.
+pub fn path(text: &str) -> ast::Path {
+    ast_from_text(&format!("fn f() {{ {}; }}", text))
+}
+
+pub fn path_segment(name_ref: ast::NameRef) -> ast::PathSegment {
+    ast_from_text(&format!("use {};", name_ref.syntax().text()))
+}
+
+pub fn path_qualified(qualifier: ast::Path, segment: ast::PathSegment) -> ast::Path {
+    path(&format!("{}::{}", qualifier.syntax().text(), segment.syntax().text()))
+}
+
+pub fn expr_path(path: ast::Path) -> ast::Expr {
+    expr_from_text(&path.syntax().text().to_string())
+}
+
+pub fn expr_literal(text: &str) -> ast::Literal {
+    ast_from_text(&format!("fn f() {{ {}; }}", text))
+}
+
+pub fn arg_list<I: IntoIterator<Item = ast::Expr>>(args: I) -> ast::ArgList {
+    ast_from_text(&format!("fn f() {{ f({}); }}", join(args, ", ")))
+}
+
+pub fn expr_call(callee: ast::Expr, args: ast::ArgList) -> ast::Expr {
+    expr_from_text(&format!("{}{}", callee.syntax().text(), args.syntax().text()))
+}
+
+pub fn expr_match<I>(expr: ast::Expr, arms: I) -> ast::Expr
+    where I: IntoIterator<Item = ast::MatchArm>
+{
+    let arms = arms.into_iter()
+        .map(|arm| format!("    {},\n", arm.syntax().text()))
+        .join("");
+    expr_from_text(&format!("match {} {{\n{}}}", expr.syntax().text(), arms))
+}
+
+pub fn match_arm<I>(pats: I, expr: ast::Expr) -> ast::MatchArm
+    where I: IntoIterator<Item = ast::Pat>
+{
+    let pats = join(pats, " | ");
+    ast_from_text(&format!("fn f() {{ match () {{ {} => {} }} }}", pats, expr.syntax().text()))
+}
+
+pub fn bind_pat(name: ast::Name) -> ast::BindPat {
+    ast_from_text(&format!("fn f({}: ()) {{}}", name.syntax().text()))
+}
+
+pub fn placeholder_pat() -> ast::PlaceholderPat {
+    ast_from_text("fn f(_: ()) {}")
+}
+
+pub fn param(pat: ast::Pat, ty: &str) -> ast::Param {
+    ast_from_text(&format!("fn f({}: {}) {{}}", pat.syntax().text(), ty))
+}
+
+pub fn param_list<I: IntoIterator<Item = ast::Param>>(params: I) -> ast::ParamList {
+    ast_from_text(&format!("fn f({}) {{}}", join(params, ", ")))
+}
+
+/// A block with the given statements and an optional tail expression.
+pub fn block_expr<I>(stmts: I, tail: Option<ast::Expr>) -> ast::BlockExpr
+    where I: IntoIterator<Item = ast::Stmt>
+{
+    let mut buf = String::from("{\n");
+    for stmt in stmts {
+        buf += &format!("    {}\n", stmt.syntax().text());
+    }
+    if let Some(tail) = tail {
+        buf += &format!("    {}\n", tail.syntax().text());
+    }
+    buf += "}";
+    ast_from_text(&format!("fn f() {}", buf))
+}
+
+pub fn expr_stmt(expr: ast::Expr) -> ast::Stmt {
+    ast_from_text(&format!("fn f() {{ {}; }}", expr.syntax().text()))
+}
+
+pub fn fn_def(name: ast::Name, params: ast::ParamList, body: ast::BlockExpr) -> ast::FnDef {
+    ast_from_text(&format!(
+        "fn {}{} {}",
+        name.syntax().text(), params.syntax().text(), body.syntax().text(),
+    ))
+}
+
+/// An attribute, `text` is the part between `#[` and `]`, like `derive(Clone)`.
+pub fn attr(text: &str) -> ast::Attr {
+    ast_from_text(&format!("#[{}]\nstruct S;", text))
+}
+
+/// A path of a `use` item, like `std::sync::Arc` or `self::foo`.
+/// Unlike the paths made by `path`, it can't contain generic
+/// arguments or a `<T as Trait>` qualifier.
+pub fn use_path(text: &str) -> ast::Path {
+    ast_from_text(&format!("use {};", text))
+}
+
+/// `path` should be made by `use_path`. Panics on paths which are valid
+/// only in expressions, like the ones with a turbofish.
+pub fn use_tree(path: ast::Path, alias: Option<ast::Name>) -> ast::UseTree {
+    let text = match alias {
+        Some(alias) => format!("use {} as {};", path.syntax().text(), alias.syntax().text()),
+        None => format!("use {};", path.syntax().text()),
+    };
+    ast_from_text(&text)
+}
+
+pub fn use_item(use_tree: ast::UseTree) -> ast::UseItem {
+    ast_from_text(&format!("use {};", use_tree.syntax().text()))
+}
+
+fn expr_from_text(text: &str) -> ast::Expr {
+    // The body of `f` is an expression too, so look for the statement.
+    let stmt: ast::ExprStmt = ast_from_text(&format!("fn f() {{ {}; }}", text));
+    detach(stmt.expr().unwrap())
+}
+
+fn join<N, I>(nodes: I, sep: &str) -> String
+    where N: AstNode<Arc<SyntaxRoot>>,
+          I: IntoIterator<Item = N>,
+{
+    nodes.into_iter()
+        .map(|it| it.syntax().text().to_string())
+        .join(sep)
+}
+
+fn ast_from_text<N: AstNode<Arc<SyntaxRoot>>>(text: &str) -> N {
+    let file = ast::File::parse(text);
+    let errors = file.errors();
+    assert!(errors.is_empty(), "invalid template {:?}: {:?}", text, errors);
+    let node = find::<N>(file.syntax().clone())
+        .unwrap_or_else(|| panic!("template {:?} has no node of the requested type", text));
+    detach(N::cast(node).unwrap())
+}
+
+fn detach<N: AstNode<Arc<SyntaxRoot>>>(node: N) -> N {
+    let green = node.syntax().green().clone();
+    N::cast(SyntaxNode::new_owned(SyntaxRoot::new(green, Vec::new()))).unwrap()
+}
+
+fn find<N: AstNode<Arc<SyntaxRoot>>>(node: SyntaxNode) -> Option<SyntaxNode> {
+    if N::cast(node.clone()).is_some() {
+        return Some(node);
+    }
+    node.children().filter_map(find::<N>).next()
+}
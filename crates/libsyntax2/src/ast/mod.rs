@@ -1,15 +1,23 @@
 mod generated;
+mod tokens;
+pub mod make;
 
-use std::sync::Arc;
+use std::{marker::PhantomData, ops::DerefMut, sync::Arc};
 
 use itertools::Itertools;
 use smol_str::SmolStr;
 
 use {
-    SyntaxNode, SyntaxNodeRef, SyntaxRoot, TreeRoot, SyntaxError,
-    SyntaxKind::*,
+    SyntaxNode, SyntaxNodeRef, SyntaxToken, SyntaxRoot, TreeRoot, SyntaxError, AtomEdit, GreenInterner,
+    algo::walk::Preorder,
+    SyntaxKind::{self, *},
+    reparsing::incremental_reparse,
+    text_utils::replace_range,
+};
+pub use self::{
+    generated::*,
+    tokens::*,
 };
-pub use self::generated::*;
 
 pub trait AstNode<R: TreeRoot> {
     fn cast(syntax: SyntaxNode<R>) -> Option<Self>
@@ -18,6 +26,37 @@ pub trait AstNode<R: TreeRoot> {
     fn syntax_ref<'a>(&'a self) -> SyntaxNodeRef<'a> where R: 'a {
         self.syntax().as_ref()
     }
+    /// All nodes of type `N` in the subtree of this node, in preorder.
+    fn descendants<N: AstNode<R>>(&self) -> AstDescendants<R, N> {
+        AstDescendants { inner: self.syntax().descendants(), ph: PhantomData }
+    }
+}
+
+/// An iterator over the typed descendants of a node,
+/// see `AstNode::descendants`.
+#[derive(Debug)]
+pub struct AstDescendants<R: TreeRoot, N> {
+    inner: Preorder<R>,
+    ph: PhantomData<N>,
+}
+
+impl<R: TreeRoot, N: AstNode<R>> Iterator for AstDescendants<R, N> {
+    type Item = N;
+
+    fn next(&mut self) -> Option<N> {
+        self.inner.by_ref().filter_map(N::cast).next()
+    }
+}
+
+/// Like `AstNode`, but for tokens which have a
+/// meaningful value, like literals.
+pub trait AstToken<R: TreeRoot> {
+    fn cast(syntax: SyntaxToken<R>) -> Option<Self>
+        where Self: Sized;
+    fn syntax(&self) -> &SyntaxToken<R>;
+    fn text<'a>(&'a self) -> &'a SmolStr where R: 'a {
+        self.syntax().text()
+    }
 }
 
 pub trait NameOwner<R: TreeRoot>: AstNode<R> {
@@ -41,9 +80,43 @@ impl File<Arc<SyntaxRoot>> {
     pub fn parse(text: &str) -> Self {
         File::cast(::parse(text)).unwrap()
     }
+
+    pub fn parse_with_interner(text: &str, interner: &mut GreenInterner) -> Self {
+        File::cast(::parse_with_interner(text, interner)).unwrap()
+    }
+
+    pub fn parse_with_shared_interner<I, F>(text: &str, lock: F) -> Self
+    where
+        I: DerefMut<Target = GreenInterner>,
+        F: FnOnce() -> I,
+    {
+        File::cast(::parse_with_shared_interner(text, lock)).unwrap()
+    }
+
+    /// Applies `edit` to the text of the file and parses the result,
+    /// reusing as much of the existing tree as possible.
+    pub fn reparse(&self, edit: &AtomEdit) -> Self {
+        self.incremental_reparse(edit)
+            .unwrap_or_else(|| self.full_reparse(edit))
+    }
+
+    /// Like `reparse`, but returns `None` instead of falling
+    /// back to parsing the whole file from scratch.
+    pub fn incremental_reparse(&self, edit: &AtomEdit) -> Option<Self> {
+        let errors = self.syntax().root.errors.clone();
+        let root = incremental_reparse(self.syntax_ref(), edit, errors)?;
+        Some(File::cast(root).unwrap())
+    }
+
+    fn full_reparse(&self, edit: &AtomEdit) -> Self {
+        let text = replace_range(self.syntax().text().to_string(), edit.delete, &edit.insert);
+        File::parse(&text)
+    }
 }
 
 impl<R: TreeRoot> File<R> {
+    /// Errors from the lexer, the parser and the validation
+    /// of the tree, sorted by position.
     pub fn errors(&self) -> Vec<SyntaxError> {
         self.syntax().root.errors.clone()
     }
@@ -55,14 +128,32 @@ impl<R: TreeRoot> FnDef<R> {
             .filter_map(|x| x.as_atom())
             .any(|x| x == atom)
     }
+
+    pub fn is_default(&self) -> bool {
+        has_child_of_kind(self.syntax(), DEFAULT_KW)
+    }
+
+    pub fn is_async(&self) -> bool {
+        has_child_of_kind(self.syntax(), ASYNC_KW)
+    }
+}
+
+impl<R: TreeRoot> TraitDef<R> {
+    pub fn is_unsafe(&self) -> bool {
+        has_child_of_kind(self.syntax(), UNSAFE_KW)
+    }
+
+    pub fn is_auto(&self) -> bool {
+        has_child_of_kind(self.syntax(), AUTO_KW)
+    }
 }
 
 impl<R: TreeRoot> Attr<R> {
     pub fn as_atom(&self) -> Option<SmolStr> {
         let tt = self.value()?;
-        let (_bra, attr, _ket) = tt.syntax().children().collect_tuple()?;
+        let (_bra, attr, _ket) = tt.syntax().children_with_tokens().collect_tuple()?;
         if attr.kind() == IDENT {
-            Some(attr.leaf_text().unwrap())
+            Some(attr.as_token()?.text().clone())
         } else {
             None
         }
@@ -70,10 +161,10 @@ impl<R: TreeRoot> Attr<R> {
 
     pub fn as_call(&self) -> Option<(SmolStr, TokenTree<R>)> {
         let tt = self.value()?;
-        let (_bra, attr, args, _ket) = tt.syntax().children().collect_tuple()?;
-        let args = TokenTree::cast(args)?;
+        let (_bra, attr, args, _ket) = tt.syntax().children_with_tokens().collect_tuple()?;
+        let args = TokenTree::cast(args.as_node()?)?;
         if attr.kind() == IDENT {
-            Some((attr.leaf_text().unwrap(), args))
+            Some((attr.as_token()?.text().clone(), args))
         } else {
             None
         }
@@ -81,21 +172,65 @@ impl<R: TreeRoot> Attr<R> {
 }
 
 impl<R: TreeRoot> Name<R> {
+    /// The text of the identifier, without the `r#` of raw identifiers.
     pub fn text(&self) -> SmolStr {
-        let ident = self.syntax().first_child()
-            .unwrap();
-        ident.leaf_text().unwrap()
+        ident_text(self.syntax())
+    }
+
+    /// Is this a raw identifier, like `r#match`?
+    pub fn is_raw(&self) -> bool {
+        self.syntax().first_token().unwrap().text().starts_with("r#")
     }
 }
 
 impl<R: TreeRoot> NameRef<R> {
+    /// The text of the identifier, without the `r#` of raw identifiers.
     pub fn text(&self) -> SmolStr {
-        let ident = self.syntax().first_child()
-            .unwrap();
-        ident.leaf_text().unwrap()
+        ident_text(self.syntax())
+    }
+
+    /// Is this a raw identifier, like `r#match`?
+    pub fn is_raw(&self) -> bool {
+        self.syntax().first_token().unwrap().text().starts_with("r#")
     }
 }
 
+impl<R: TreeRoot> Literal<R> {
+    pub fn token(&self) -> SyntaxToken<R> {
+        self.syntax().first_token().unwrap()
+    }
+
+    pub fn kind(&self) -> LiteralKind<R> {
+        let token = self.token();
+        match token.kind() {
+            STRING => LiteralKind::String(String::cast(token).unwrap()),
+            RAW_STRING => LiteralKind::RawString(RawString::cast(token).unwrap()),
+            CHAR => LiteralKind::Char(Char::cast(token).unwrap()),
+            BYTE => LiteralKind::Byte(Byte::cast(token).unwrap()),
+            BYTE_STRING => LiteralKind::ByteString(ByteString::cast(token).unwrap()),
+            RAW_BYTE_STRING => LiteralKind::RawByteString(RawByteString::cast(token).unwrap()),
+            INT_NUMBER => LiteralKind::IntNumber(IntNumber::cast(token).unwrap()),
+            FLOAT_NUMBER => LiteralKind::FloatNumber(FloatNumber::cast(token).unwrap()),
+            TRUE_KW => LiteralKind::Bool(true),
+            FALSE_KW => LiteralKind::Bool(false),
+            kind => unreachable!("unexpected literal token {:?}", kind),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum LiteralKind<R: TreeRoot = Arc<SyntaxRoot>> {
+    String(String<R>),
+    RawString(RawString<R>),
+    Char(Char<R>),
+    Byte(Byte<R>),
+    ByteString(ByteString<R>),
+    RawByteString(RawByteString<R>),
+    IntNumber(IntNumber<R>),
+    FloatNumber(FloatNumber<R>),
+    Bool(bool),
+}
+
 impl <R: TreeRoot> ImplItem<R> {
     pub fn target_type(&self) -> Option<TypeRef<R>> {
         match self.target() {
@@ -111,6 +246,14 @@ impl <R: TreeRoot> ImplItem<R> {
         }
     }
 
+    pub fn is_unsafe(&self) -> bool {
+        has_child_of_kind(self.syntax(), UNSAFE_KW)
+    }
+
+    pub fn is_default(&self) -> bool {
+        has_child_of_kind(self.syntax(), DEFAULT_KW)
+    }
+
     fn target(&self) -> (Option<TypeRef<R>>, Option<TypeRef<R>>) {
         let mut types = self.syntax().children().filter_map(TypeRef::cast);
         let first = types.next();
@@ -118,3 +261,55 @@ impl <R: TreeRoot> ImplItem<R> {
         (first, second)
     }
 }
+
+impl<R: TreeRoot> MacroRule<R> {
+    pub fn matcher(&self) -> Option<TokenTree<R>> {
+        self.token_trees().next()
+    }
+
+    pub fn transcriber(&self) -> Option<TokenTree<R>> {
+        self.token_trees().nth(1)
+    }
+
+    fn token_trees<'a>(&'a self) -> impl Iterator<Item = TokenTree<R>> + 'a {
+        self.syntax().children().filter_map(TokenTree::cast)
+    }
+}
+
+impl<R: TreeRoot> IfExpr<R> {
+    pub fn else_branch(&self) -> Option<Expr<R>> {
+        self.syntax()
+            .children_with_tokens()
+            .skip_while(|it| it.kind() != ELSE_KW)
+            .filter_map(|it| it.as_node())
+            .filter_map(Expr::cast)
+            .next()
+    }
+}
+
+impl<R: TreeRoot> BinExpr<R> {
+    pub fn lhs(&self) -> Option<Expr<R>> {
+        self.exprs().next()
+    }
+
+    pub fn rhs(&self) -> Option<Expr<R>> {
+        self.exprs().nth(1)
+    }
+
+    fn exprs<'a>(&'a self) -> impl Iterator<Item = Expr<R>> + 'a {
+        self.syntax().children().filter_map(Expr::cast)
+    }
+}
+
+fn ident_text<R: TreeRoot>(node: &SyntaxNode<R>) -> SmolStr {
+    let text = node.first_token().unwrap().text().clone();
+    if text.starts_with("r#") {
+        SmolStr::new(&text[2..])
+    } else {
+        text
+    }
+}
+
+fn has_child_of_kind<R: TreeRoot>(node: &SyntaxNode<R>, kind: SyntaxKind) -> bool {
+    node.children_with_tokens().any(|it| it.kind() == kind)
+}
@@ -0,0 +1,292 @@
+//! Typed wrappers for literal tokens, which give access to their values.
+use std::sync::Arc;
+
+use {
+    ast::AstToken,
+    unescape::{unescape, Mode},
+    SyntaxToken, SyntaxRoot, TreeRoot, TextRange, TextUnit,
+    SyntaxKind::*,
+};
+
+/// An error which prevents computing the value of a literal,
+/// like an invalid escape sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiteralError {
+    /// The range of the offending part of the literal in the file.
+    pub range: TextRange,
+    pub msg: &'static str,
+}
+
+macro_rules! ast_tokens {
+    ($($(#[$doc:meta])* $name:ident = $kind:ident;)*) => {$(
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name<R: TreeRoot = Arc<SyntaxRoot>> {
+            syntax: SyntaxToken<R>,
+        }
+
+        impl<R: TreeRoot> AstToken<R> for $name<R> {
+            fn cast(syntax: SyntaxToken<R>) -> Option<Self> {
+                match syntax.kind() {
+                    $kind => Some($name { syntax }),
+                    _ => None,
+                }
+            }
+            fn syntax(&self) -> &SyntaxToken<R> { &self.syntax }
+        }
+    )*};
+}
+
+ast_tokens! {
+    /// A string literal, like `"hello\n"`.
+    String = STRING;
+    /// A raw string literal, like `r#"hello"#`.
+    RawString = RAW_STRING;
+    /// A character literal, like `'\n'`.
+    Char = CHAR;
+    /// A byte literal, like `b'\n'`.
+    Byte = BYTE;
+    /// A byte string literal, like `b"hello\n"`.
+    ByteString = BYTE_STRING;
+    /// A raw byte string literal, like `br#"hello"#`.
+    RawByteString = RAW_BYTE_STRING;
+    /// An integer literal, like `0xFF_u8`.
+    IntNumber = INT_NUMBER;
+    /// A floating point literal, like `1.5e3f32`.
+    FloatNumber = FLOAT_NUMBER;
+}
+
+impl<R: TreeRoot> String<R> {
+    pub fn value(&self) -> Result<::std::string::String, Vec<LiteralError>> {
+        let chars = unescape_quoted(&self.syntax, 0, '"', Mode::Str)?;
+        Ok(chars.into_iter().collect())
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        quoted_suffix(self.text(), 0, '"')
+    }
+}
+
+impl<R: TreeRoot> RawString<R> {
+    /// The contents of the literal, `None` if it is unterminated.
+    pub fn value(&self) -> Option<&str> {
+        let (start, end, _) = raw_parts(self.text(), 0)?;
+        Some(&self.text()[start..end])
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        let (_, _, suffix_start) = raw_parts(self.text(), 0)?;
+        non_empty(&self.text()[suffix_start..])
+    }
+}
+
+impl<R: TreeRoot> Char<R> {
+    pub fn value(&self) -> Result<char, Vec<LiteralError>> {
+        let chars = unescape_quoted(&self.syntax, 0, '\'', Mode::Char)?;
+        single(&self.syntax, &chars, Mode::Char)
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        quoted_suffix(self.text(), 0, '\'')
+    }
+}
+
+impl<R: TreeRoot> Byte<R> {
+    pub fn value(&self) -> Result<u8, Vec<LiteralError>> {
+        let chars = unescape_quoted(&self.syntax, 1, '\'', Mode::Byte)?;
+        single(&self.syntax, &chars, Mode::Byte).map(|c| c as u8)
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        quoted_suffix(self.text(), 1, '\'')
+    }
+}
+
+impl<R: TreeRoot> ByteString<R> {
+    pub fn value(&self) -> Result<Vec<u8>, Vec<LiteralError>> {
+        let chars = unescape_quoted(&self.syntax, 1, '"', Mode::ByteStr)?;
+        Ok(chars.into_iter().map(|c| c as u8).collect())
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        quoted_suffix(self.text(), 1, '"')
+    }
+}
+
+impl<R: TreeRoot> RawByteString<R> {
+    /// The contents of the literal, `None` if it is unterminated.
+    pub fn value(&self) -> Option<&[u8]> {
+        let (start, end, _) = raw_parts(self.text(), 1)?;
+        Some(self.text()[start..end].as_bytes())
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        let (_, _, suffix_start) = raw_parts(self.text(), 1)?;
+        non_empty(&self.text()[suffix_start..])
+    }
+}
+
+impl<R: TreeRoot> IntNumber<R> {
+    pub fn radix(&self) -> u32 {
+        split_int(self.text()).0
+    }
+
+    /// The value of the literal, `None` if it has invalid
+    /// digits or does not fit into `u128`.
+    pub fn value(&self) -> Option<u128> {
+        let (radix, prefix_len, suffix_start) = split_int(self.text());
+        let digits = &self.text()[prefix_len..suffix_start];
+        if !digits.chars().any(|c| c != '_') {
+            return None;
+        }
+        digits.chars()
+            .filter(|&c| c != '_')
+            .try_fold(0u128, |acc, c| {
+                acc.checked_mul(radix as u128)?.checked_add(c.to_digit(radix)? as u128)
+            })
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        let (_, _, suffix_start) = split_int(self.text());
+        non_empty(&self.text()[suffix_start..])
+    }
+}
+
+impl<R: TreeRoot> FloatNumber<R> {
+    pub fn value(&self) -> Option<f64> {
+        let suffix_start = float_suffix_start(self.text());
+        let text: ::std::string::String = self.text()[..suffix_start]
+            .chars()
+            .filter(|&c| c != '_')
+            .collect();
+        text.parse().ok()
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        non_empty(&self.text()[float_suffix_start(self.text())..])
+    }
+}
+
+/// Splits an integer literal into the radix, the length of the
+/// radix prefix like `0x`, and the start of the suffix.
+pub(crate) fn split_int(text: &str) -> (u32, usize, usize) {
+    let (radix, prefix_len) = match text.get(..2) {
+        Some("0x") => (16, 2),
+        Some("0o") => (8, 2),
+        Some("0b") => (2, 2),
+        _ => (10, 0),
+    };
+    // Decimal digits are accepted in any radix, so that `0b12` is
+    // reported as an invalid digit rather than an invalid suffix.
+    let digit_radix = if radix == 16 { 16 } else { 10 };
+    let suffix_start = text[prefix_len..]
+        .find(|c: char| !(c == '_' || c.is_digit(digit_radix)))
+        .map(|it| it + prefix_len)
+        .unwrap_or(text.len());
+    (radix, prefix_len, suffix_start)
+}
+
+pub(crate) fn float_suffix_start(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut i = skip_digits(bytes, 0);
+    if bytes.get(i) == Some(&b'.') {
+        i = skip_digits(bytes, i + 1);
+    }
+    if bytes.get(i) == Some(&b'e') || bytes.get(i) == Some(&b'E') {
+        i += 1;
+        if bytes.get(i) == Some(&b'+') || bytes.get(i) == Some(&b'-') {
+            i += 1;
+        }
+        i = skip_digits(bytes, i);
+    }
+    i
+}
+
+fn skip_digits(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && (bytes[i] == b'_' || bytes[i].is_ascii_digit()) {
+        i += 1;
+    }
+    i
+}
+
+/// The position of the closing quote, if the literal is terminated.
+fn closing_quote(text: &str, prefix_len: usize, quote: char) -> Option<usize> {
+    match text.rfind(quote) {
+        Some(it) if it > prefix_len => Some(it),
+        _ => None,
+    }
+}
+
+fn quoted_suffix(text: &str, prefix_len: usize, quote: char) -> Option<&str> {
+    let close = closing_quote(text, prefix_len, quote)?;
+    non_empty(&text[close + 1..])
+}
+
+fn unescape_quoted<R: TreeRoot>(
+    token: &SyntaxToken<R>,
+    prefix_len: usize,
+    quote: char,
+    mode: Mode,
+) -> Result<Vec<char>, Vec<LiteralError>> {
+    let text = token.text().as_str();
+    let start = token.range().start();
+    let close = match closing_quote(text, prefix_len, quote) {
+        Some(it) => it,
+        None => {
+            let msg = "unterminated literal";
+            return Err(vec![LiteralError { range: token.range(), msg }]);
+        }
+    };
+    let body_start = start + TextUnit::from(prefix_len as u32 + 1);
+    let mut chars = Vec::new();
+    let mut errors = Vec::new();
+    unescape(&text[prefix_len + 1..close], mode, &mut |range, res| match res {
+        Ok(c) => chars.push(c),
+        Err(msg) => {
+            let range = TextRange::from_to(body_start + range.start(), body_start + range.end());
+            errors.push(LiteralError { range, msg });
+        }
+    });
+    if errors.is_empty() {
+        Ok(chars)
+    } else {
+        Err(errors)
+    }
+}
+
+fn single<R: TreeRoot>(
+    token: &SyntaxToken<R>,
+    chars: &[char],
+    mode: Mode,
+) -> Result<char, Vec<LiteralError>> {
+    let msg = match (chars.len(), mode) {
+        (1, _) => return Ok(chars[0]),
+        (0, Mode::Byte) => "empty byte literal",
+        (0, _) => "empty character literal",
+        (_, Mode::Byte) => "byte literal may only contain one byte",
+        _ => "character literal may only contain one codepoint",
+    };
+    Err(vec![LiteralError { range: token.range(), msg }])
+}
+
+/// Splits a raw string literal into the start and the end of
+/// the contents and the start of the suffix.
+fn raw_parts(text: &str, prefix_len: usize) -> Option<(usize, usize, usize)> {
+    let after_r = prefix_len + 1;
+    let n_hashes = text[after_r..].chars().take_while(|&c| c == '#').count();
+    let start = after_r + n_hashes + 1;
+    if text.get(start - 1..start) != Some("\"") {
+        return None;
+    }
+    let closing = format!("\"{}", "#".repeat(n_hashes));
+    let end = text[start..].find(closing.as_str())? + start;
+    Some((start, end, end + closing.len()))
+}
+
+fn non_empty(text: &str) -> Option<&str> {
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
@@ -25,7 +25,7 @@ fn attribute(p: &mut Parser, inner: bool) {
     if p.at(L_BRACK) {
         items::token_tree(p);
     } else {
-        p.error("expected `[`");
+        p.error_expected_tokens(ErrorCode::ExpectedToken, &[L_BRACK]);
     }
     attr.complete(p, ATTR);
 }
@@ -30,7 +30,8 @@ pub(super) const ATOM_EXPR_FIRST: TokenSet =
     token_set_union![
         LITERAL_FIRST,
         token_set![L_PAREN, PIPE, MOVE_KW, IF_KW, WHILE_KW, MATCH_KW, UNSAFE_KW, L_CURLY, RETURN_KW,
-                   IDENT, SELF_KW, SUPER_KW, COLONCOLON ],
+                   IDENT, SELF_KW, SUPER_KW, COLONCOLON, LOOP_KW, FOR_KW, BREAK_KW, CONTINUE_KW,
+                   LIFETIME, YIELD_KW ],
     ];
 
 pub(super) fn atom_expr(p: &mut Parser, r: Restrictions) -> Option<CompletedMarker> {
@@ -38,25 +39,58 @@ pub(super) fn atom_expr(p: &mut Parser, r: Restrictions) -> Option<CompletedMark
         Some(m) => return Some(m),
         None => (),
     }
+    let la = p.nth(1);
+    if p.at(IDENT) {
+        if p.at_contextual_kw("async") && (la == L_CURLY || la == MOVE_KW && p.nth(2) == L_CURLY) {
+            return Some(async_block_expr(p));
+        }
+        if p.at_contextual_kw("try") && la == L_CURLY {
+            return Some(try_block_expr(p));
+        }
+    }
     if paths::is_path_start(p) || p.at(L_ANGLE) {
         return Some(path_expr(p, r));
     }
-    let la = p.nth(1);
     let done = match p.current() {
         L_PAREN => tuple_expr(p),
         L_BRACK => array_expr(p),
         PIPE => lambda_expr(p),
         MOVE_KW if la == PIPE => lambda_expr(p),
         IF_KW => if_expr(p),
-        WHILE_KW => while_expr(p),
-        LOOP_KW => loop_expr(p),
-        FOR_KW => for_expr(p),
+        WHILE_KW => while_expr(p, None),
+        LOOP_KW => loop_expr(p, None),
+        FOR_KW => for_expr(p, None),
+        // test labeled_loops
+        // fn foo() {
+        //     'a: loop {};
+        //     'b: while true {};
+        //     'c: for x in () {};
+        //     'd: {};
+        // }
+        LIFETIME if la == COLON => {
+            let m = p.start();
+            label(p);
+            match p.current() {
+                LOOP_KW => loop_expr(p, Some(m)),
+                WHILE_KW => while_expr(p, Some(m)),
+                FOR_KW => for_expr(p, Some(m)),
+                L_CURLY => block_expr(p, Some(m)),
+                _ => {
+                    p.error_expected(ErrorCode::ExpectedLoop, "a loop");
+                    m.complete(p, ERROR);
+                    return None;
+                }
+            }
+        }
         MATCH_KW => match_expr(p),
-        UNSAFE_KW if la == L_CURLY => block_expr(p),
-        L_CURLY => block_expr(p),
+        UNSAFE_KW if la == L_CURLY => block_expr(p, None),
+        L_CURLY => block_expr(p, None),
         RETURN_KW => return_expr(p),
+        YIELD_KW => yield_expr(p),
+        CONTINUE_KW => continue_expr(p),
+        BREAK_KW => break_expr(p, r),
         _ => {
-            p.err_and_bump("expected expression");
+            p.err_and_bump(ErrorCode::ExpectedExpression, "an expression");
             return None;
         }
     };
@@ -167,9 +201,9 @@ fn if_expr(p: &mut Parser) -> CompletedMarker {
 //     while true {};
 //     while let Some(x) = it.next() {};
 // }
-fn while_expr(p: &mut Parser) -> CompletedMarker {
+fn while_expr(p: &mut Parser, m: Option<Marker>) -> CompletedMarker {
     assert!(p.at(WHILE_KW));
-    let m = p.start();
+    let m = m.unwrap_or_else(|| p.start());
     p.bump();
     cond(p);
     block(p);
@@ -180,9 +214,9 @@ fn while_expr(p: &mut Parser) -> CompletedMarker {
 // fn foo() {
 //     loop {};
 // }
-fn loop_expr(p: &mut Parser) -> CompletedMarker {
+fn loop_expr(p: &mut Parser, m: Option<Marker>) -> CompletedMarker {
     assert!(p.at(LOOP_KW));
-    let m = p.start();
+    let m = m.unwrap_or_else(|| p.start());
     p.bump();
     block(p);
     m.complete(p, LOOP_EXPR)
@@ -192,9 +226,9 @@ fn loop_expr(p: &mut Parser) -> CompletedMarker {
 // fn foo() {
 //     for x in [] {};
 // }
-fn for_expr(p: &mut Parser) -> CompletedMarker {
+fn for_expr(p: &mut Parser, m: Option<Marker>) -> CompletedMarker {
     assert!(p.at(FOR_KW));
-    let m = p.start();
+    let m = m.unwrap_or_else(|| p.start());
     p.bump();
     patterns::pattern(p);
     p.expect(IN_KW);
@@ -203,14 +237,24 @@ fn for_expr(p: &mut Parser) -> CompletedMarker {
     m.complete(p, FOR_EXPR)
 }
 
+fn label(p: &mut Parser) {
+    assert!(p.at(LIFETIME) && p.nth(1) == COLON);
+    let m = p.start();
+    p.bump();
+    p.bump();
+    m.complete(p, LABEL);
+}
+
 // test cond
 // fn foo() { if let Some(_) = None {} }
 fn cond(p: &mut Parser) {
+    let m = p.start();
     if p.eat(LET_KW) {
         patterns::pattern(p);
         p.expect(EQ);
     }
-    expr_no_struct(p)
+    expr_no_struct(p);
+    m.complete(p, CONDITION);
 }
 
 // test match_expr
@@ -224,7 +268,8 @@ fn match_expr(p: &mut Parser) -> CompletedMarker {
     p.bump();
     expr_no_struct(p);
     p.eat(L_CURLY);
-    while !p.at(EOF) && !p.at(R_CURLY) {
+    let indented = block_is_indented(p);
+    while !p.at(EOF) && !p.at(R_CURLY) && !at_unclosed_block_end(p, indented) {
         // test match_arms_commas
         // fn foo() {
         //     match () {
@@ -258,8 +303,8 @@ fn match_arm(p: &mut Parser) -> BlockLike {
             break;
         }
     }
-    if p.eat(IF_KW) {
-        expr_no_struct(p);
+    if p.at(IF_KW) {
+        match_guard(p);
     }
     p.expect(FAT_ARROW);
     let ret = expr_stmt(p);
@@ -267,17 +312,26 @@ fn match_arm(p: &mut Parser) -> BlockLike {
     ret
 }
 
+fn match_guard(p: &mut Parser) {
+    assert!(p.at(IF_KW));
+    let m = p.start();
+    p.bump();
+    expr_no_struct(p);
+    m.complete(p, MATCH_GUARD);
+}
+
 // test block_expr
 // fn foo() {
 //     {};
 //     unsafe {};
 // }
-pub(super) fn block_expr(p: &mut Parser) -> CompletedMarker {
+pub(super) fn block_expr(p: &mut Parser, m: Option<Marker>) -> CompletedMarker {
     assert!(p.at(L_CURLY) || p.at(UNSAFE_KW) && p.nth(1) == L_CURLY);
-    let m = p.start();
+    let m = m.unwrap_or_else(|| p.start());
     p.eat(UNSAFE_KW);
     p.bump();
-    while !p.at(EOF) && !p.at(R_CURLY) {
+    let indented = block_is_indented(p);
+    while !p.at(EOF) && !p.at(R_CURLY) && !at_unclosed_block_end(p, indented) {
         match p.current() {
             LET_KW => let_stmt(p),
             _ => {
@@ -290,7 +344,7 @@ pub(super) fn block_expr(p: &mut Parser) -> CompletedMarker {
                     }
                     items::MaybeItem::Modifiers => {
                         m.abandon(p);
-                        p.error("expected an item");
+                        p.error_expected(ErrorCode::ExpectedItem, "an item");
                     }
                     // test pub_expr
                     // fn foo() { pub 92; } //FIXME
@@ -310,6 +364,32 @@ pub(super) fn block_expr(p: &mut Parser) -> CompletedMarker {
     m.complete(p, BLOCK_EXPR)
 }
 
+// test async_block_expr
+// fn foo() {
+//     async {};
+//     async move {};
+// }
+fn async_block_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at_contextual_kw("async"));
+    let m = p.start();
+    p.bump_remap(ASYNC_KW);
+    p.eat(MOVE_KW);
+    block_expr(p, None);
+    m.complete(p, ASYNC_BLOCK_EXPR)
+}
+
+// test try_block_expr
+// fn foo() {
+//     let _ = try {};
+// }
+fn try_block_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at_contextual_kw("try"));
+    let m = p.start();
+    p.bump_remap(TRY_KW);
+    block_expr(p, None);
+    m.complete(p, TRY_BLOCK_EXPR)
+}
+
 // test let_stmt;
 // fn foo() {
 //     let a;
@@ -346,3 +426,62 @@ fn return_expr(p: &mut Parser) -> CompletedMarker {
     }
     m.complete(p, RETURN_EXPR)
 }
+
+// test yield_expr
+// fn foo() {
+//     yield;
+//     yield 1;
+// }
+fn yield_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(YIELD_KW));
+    let m = p.start();
+    p.bump();
+    if EXPR_FIRST.contains(p.current()) {
+        expr(p);
+    }
+    m.complete(p, YIELD_EXPR)
+}
+
+// test continue_expr
+// fn foo() {
+//     loop {
+//         continue;
+//         continue 'l;
+//     }
+// }
+fn continue_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(CONTINUE_KW));
+    let m = p.start();
+    p.bump();
+    p.eat(LIFETIME);
+    m.complete(p, CONTINUE_EXPR)
+}
+
+// test break_expr
+// fn foo() {
+//     loop {
+//         break;
+//         break 'l;
+//         break 92;
+//         break 'l 92;
+//     }
+// }
+fn break_expr(p: &mut Parser, r: Restrictions) -> CompletedMarker {
+    assert!(p.at(BREAK_KW));
+    let m = p.start();
+    p.bump();
+    p.eat(LIFETIME);
+    // test break_ambiguity
+    // fn foo(){
+    //     if break {}
+    //     while break {}
+    //     for i in break {}
+    //     match break {}
+    //     if break x {}
+    // }
+    if EXPR_FIRST.contains(p.current()) && !(r.forbid_structs && p.at(L_CURLY)) {
+        let r = Restrictions { forbid_structs: r.forbid_structs, prefer_stmt: false };
+        expr_bp(p, r, 1);
+    }
+    m.complete(p, BREAK_EXPR)
+}
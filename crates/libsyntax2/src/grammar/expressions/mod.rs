@@ -27,10 +27,10 @@ fn expr_no_struct(p: &mut Parser) {
 // fn d() { 1; 2 }
 pub(super) fn block(p: &mut Parser) {
     if !p.at(L_CURLY) {
-        p.error("expected block");
+        p.error_expected(ErrorCode::ExpectedBlock, "a block");
         return;
     }
-    atom::block_expr(p);
+    atom::block_expr(p, None);
 }
 
 #[derive(Clone, Copy)]
@@ -145,7 +145,7 @@ fn is_block(kind: SyntaxKind) -> bool {
 
 const LHS_FIRST: TokenSet =
     token_set_union![
-        token_set![AMP, STAR, EXCL, DOTDOT, MINUS],
+        token_set![AMP, STAR, EXCL, DOTDOT, MINUS, BOX_KW],
         atom::ATOM_EXPR_FIRST,
     ];
 
@@ -174,6 +174,16 @@ fn lhs(p: &mut Parser, r: Restrictions) -> Option<CompletedMarker> {
             p.bump();
             PREFIX_EXPR
         }
+        // test box_expr
+        // fn foo() {
+        //     let x = box 1i32;
+        //     let y = (box 1i32, box 2i32);
+        // }
+        BOX_KW => {
+            m = p.start();
+            p.bump();
+            BOX_EXPR
+        }
         // test full_range_expr
         // fn foo() { xs[..]; }
         DOTDOT => {
@@ -207,6 +217,7 @@ fn postfix_expr(p: &mut Parser, r: Restrictions, mut lhs: CompletedMarker) -> Co
             // }
             L_PAREN if allow_calls => call_expr(p, lhs),
             L_BRACK if allow_calls => index_expr(p, lhs),
+            DOT if p.nth(1) == IDENT && p.nth_at_contextual_kw(1, "await") => await_expr(p, lhs),
             DOT if p.nth(1) == IDENT => if p.nth(2) == L_PAREN || p.nth(2) == COLONCOLON {
                 method_call_expr(p, lhs)
             } else {
@@ -302,6 +313,20 @@ fn try_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
     m.complete(p, TRY_EXPR)
 }
 
+// test await_expr
+// fn foo() {
+//     x.await;
+//     x.0.await;
+//     x.0().await?.hello();
+// }
+fn await_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    assert!(p.at(DOT) && p.nth_at_contextual_kw(1, "await"));
+    let m = lhs.precede(p);
+    p.bump();
+    p.bump_remap(AWAIT_KW);
+    m.complete(p, AWAIT_EXPR)
+}
+
 // test cast_expr
 // fn foo() {
 //     82 as i32;
@@ -375,7 +400,7 @@ fn struct_lit(p: &mut Parser) {
                 p.bump();
                 expr(p);
             }
-            _ => p.err_and_bump("expected identifier"),
+            _ => p.err_and_bump(ErrorCode::ExpectedName, "an identifier"),
         }
         if !p.at(R_CURLY) {
             p.expect(COMMA);
@@ -5,6 +5,8 @@ mod structs;
 mod traits;
 mod use_item;
 
+pub(super) use self::traits::{impl_item_list, trait_item_list};
+
 // test mod_contents
 // fn foo() {}
 // macro_rules! foo {}
@@ -40,16 +42,16 @@ pub(super) fn item_or_macro(p: &mut Parser, stop_on_r_curly: bool, flavor: ItemF
             } else {
                 m.abandon(p);
                 if p.at(L_CURLY) {
-                    error_block(p, "expected an item");
+                    error_block(p, ErrorCode::ExpectedItem, "an item");
                 } else if !p.at(EOF) && !(stop_on_r_curly && p.at(R_CURLY)) {
-                    p.err_and_bump("expected an item");
+                    p.err_and_bump(ErrorCode::ExpectedItem, "an item");
                 } else {
-                    p.error("expected an item");
+                    p.error_expected(ErrorCode::ExpectedItem, "an item");
                 }
             }
         }
         MaybeItem::Modifiers => {
-            p.error("expected fn, trait or impl");
+            p.error_expected_tokens(ErrorCode::ExpectedItem, &[FN_KW, TRAIT_KW, IMPL_KW]);
             m.complete(p, ERROR);
         }
     }
@@ -70,7 +72,15 @@ pub(super) fn maybe_item(p: &mut Parser, flavor: ItemFlavor) -> MaybeItem {
 
     let mut has_mods = false;
     // modifiers
-    has_mods |= p.eat(CONST_KW);
+    has_mods |= p.eat_silently(CONST_KW);
+
+    // test async_fn
+    // async fn foo() {}
+    // async unsafe fn bar() {}
+    if p.at(IDENT) && p.at_contextual_kw("async") && (p.nth(1) == FN_KW || p.nth(1) == UNSAFE_KW) {
+        p.bump_remap(ASYNC_KW);
+        has_mods = true;
+    }
 
     // test unsafe_block_in_mod
     // fn foo(){} unsafe { } fn bar(){}
@@ -86,9 +96,37 @@ pub(super) fn maybe_item(p: &mut Parser, flavor: ItemFlavor) -> MaybeItem {
         p.bump_remap(AUTO_KW);
         has_mods = true;
     }
-    if p.at(IDENT) && p.at_contextual_kw("default") && p.nth(1) == IMPL_KW {
-        p.bump_remap(DEFAULT_KW);
-        has_mods = true;
+
+    // test default_item
+    // impl T for Foo {
+    //     default fn foo() {}
+    //     default unsafe fn bar() {}
+    //     default type T = Bar;
+    //     default const C: i32 = 92;
+    // }
+    if p.at(IDENT) && p.at_contextual_kw("default") {
+        match p.nth(1) {
+            FN_KW | IMPL_KW => {
+                p.bump_remap(DEFAULT_KW);
+                has_mods = true;
+            }
+            UNSAFE_KW => {
+                p.bump_remap(DEFAULT_KW);
+                p.bump();
+                if p.at(EXTERN_KW) {
+                    abi(p);
+                }
+                has_mods = true;
+            }
+            TYPE_KW | CONST_KW => {
+                p.bump_remap(DEFAULT_KW);
+                if let Some(kind) = items_without_modifiers(p) {
+                    return MaybeItem::Item(kind);
+                }
+                has_mods = true;
+            }
+            _ => (),
+        }
     }
 
     // items
@@ -165,15 +203,26 @@ fn items_without_modifiers(p: &mut Parser) -> Option<SyntaxKind> {
             MODULE
         }
         STRUCT_KW => {
-            structs::struct_def(p);
+            structs::struct_def(p, STRUCT_KW);
             if p.at(SEMI) {
-                p.err_and_bump(
+                p.err_and_bump_msg(
+                    ErrorCode::ExpectedItem,
                     "expected item, found `;`\n\
                      consider removing this semicolon"
                 );
             }
             STRUCT_DEF
         }
+        // test union_items
+        // union Foo {}
+        // union Foo {
+        //     a: i32,
+        //     b: f32,
+        // }
+        IDENT if p.at_contextual_kw("union") && la == IDENT => {
+            structs::struct_def(p, UNION_KW);
+            UNION_DEF
+        }
         ENUM_KW => {
             structs::enum_def(p);
             ENUM_DEF
@@ -190,6 +239,10 @@ fn items_without_modifiers(p: &mut Parser) -> Option<SyntaxKind> {
             consts::static_def(p);
             STATIC_DEF
         }
+        IDENT if p.at_contextual_kw("macro_rules") && la == EXCL && p.nth(2) == IDENT => {
+            macro_rules(p);
+            MACRO_RULES
+        }
         // test extern_block
         // extern {}
         EXTERN_KW if la == L_CURLY || ((la == STRING || la == RAW_STRING) && p.nth(2) == L_CURLY) => {
@@ -235,7 +288,7 @@ fn function(p: &mut Parser, flavor: ItemFlavor) {
                 params::param_list_opt_patterns(p),
         }
     } else {
-        p.error("expected function arguments");
+        p.error_expected_tokens(ErrorCode::ExpectedToken, &[L_PAREN]);
     }
     // test function_ret_type
     // fn foo() {}
@@ -285,13 +338,23 @@ fn mod_item(p: &mut Parser) {
 
     name(p);
     if !p.eat(SEMI) {
-        if p.expect(L_CURLY) {
-            mod_contents(p, true);
-            p.expect(R_CURLY);
+        if p.at(L_CURLY) {
+            mod_item_list(p);
+        } else {
+            p.error_expected_tokens(ErrorCode::ExpectedToken, &[SEMI, L_CURLY]);
         }
     }
 }
 
+pub(super) fn mod_item_list(p: &mut Parser) {
+    assert!(p.at(L_CURLY));
+    let m = p.start();
+    p.bump();
+    mod_contents(p, true);
+    p.expect(R_CURLY);
+    m.complete(p, ITEM_LIST);
+}
+
 fn macro_call(p: &mut Parser) -> BlockLike {
     assert!(paths::is_path_start(p));
     paths::use_path(p);
@@ -311,7 +374,7 @@ pub(super) fn macro_call_after_excl(p: &mut Parser) -> BlockLike {
             BlockLike::NotBlock
         }
         _ => {
-            p.error("expected `{`, `[`, `(`");
+            p.error_expected_tokens(ErrorCode::ExpectedToken, &[L_CURLY, L_BRACK, L_PAREN]);
             BlockLike::NotBlock
         },
     };
@@ -319,6 +382,63 @@ pub(super) fn macro_call_after_excl(p: &mut Parser) -> BlockLike {
     flavor
 }
 
+// test macro_rules
+// macro_rules! foo {}
+// macro_rules! bar {
+//     () => {};
+//     ($x:expr) => { $x };
+//     [$($t:tt)*] => ($($t)*)
+// }
+// macro_rules! baz(() => ());
+fn macro_rules(p: &mut Parser) {
+    assert!(p.at_contextual_kw("macro_rules") && p.nth(1) == EXCL);
+    p.bump();
+    p.bump();
+    name(p);
+    match p.current() {
+        L_CURLY => macro_rule_list(p),
+        L_PAREN | L_BRACK => {
+            macro_rule_list(p);
+            p.expect(SEMI);
+        }
+        _ => p.error_expected_tokens(ErrorCode::ExpectedToken, &[L_CURLY, L_BRACK, L_PAREN]),
+    }
+}
+
+fn macro_rule_list(p: &mut Parser) {
+    let closing_paren_kind = match p.current() {
+        L_CURLY => R_CURLY,
+        L_PAREN => R_PAREN,
+        L_BRACK => R_BRACK,
+        _ => unreachable!(),
+    };
+    p.bump();
+    while !p.at(EOF) && !p.at(closing_paren_kind) {
+        match p.current() {
+            L_CURLY | L_PAREN | L_BRACK => macro_rule(p),
+            _ => {
+                p.err_and_bump(ErrorCode::ExpectedTokenTree, "a macro matcher");
+                continue;
+            }
+        }
+        if !p.at(closing_paren_kind) {
+            p.expect(SEMI);
+        }
+    }
+    p.expect(closing_paren_kind);
+}
+
+fn macro_rule(p: &mut Parser) {
+    let m = p.start();
+    token_tree(p);
+    p.expect(FAT_ARROW);
+    match p.current() {
+        L_CURLY | L_PAREN | L_BRACK => token_tree(p),
+        _ => p.error_expected(ErrorCode::ExpectedTokenTree, "a macro transcriber"),
+    }
+    m.complete(p, MACRO_RULE);
+}
+
 pub(super) fn token_tree(p: &mut Parser) {
     let closing_paren_kind = match p.current() {
         L_CURLY => R_CURLY,
@@ -331,7 +451,9 @@ pub(super) fn token_tree(p: &mut Parser) {
     while !p.at(EOF) && !p.at(closing_paren_kind) {
         match p.current() {
             L_CURLY | L_PAREN | L_BRACK => token_tree(p),
-            R_CURLY | R_PAREN | R_BRACK => p.err_and_bump("unmatched brace"),
+            R_CURLY | R_PAREN | R_BRACK => {
+                p.err_and_bump_msg(ErrorCode::UnmatchedBrace, "unmatched brace")
+            }
             _ => p.bump()
         }
     };
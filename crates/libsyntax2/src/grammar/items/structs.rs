@@ -1,8 +1,8 @@
 use super::*;
 
-pub(super) fn struct_def(p: &mut Parser) {
-    assert!(p.at(STRUCT_KW));
-    p.bump();
+pub(super) fn struct_def(p: &mut Parser, kind: SyntaxKind) {
+    assert!(p.at(STRUCT_KW) || p.at_contextual_kw("union"));
+    p.bump_remap(kind);
 
     name(p);
     type_params::type_param_list(p);
@@ -10,29 +10,35 @@ pub(super) fn struct_def(p: &mut Parser) {
         WHERE_KW => {
             type_params::where_clause(p);
             match p.current() {
-                SEMI => {
+                SEMI if kind == STRUCT_KW => {
                     p.bump();
                     return;
                 }
                 L_CURLY => named_fields(p),
                 _ => {
                     //TODO: special case `(` error message
-                    p.error("expected `;` or `{`");
+                    let expected: &[SyntaxKind] =
+                        if kind == STRUCT_KW { &[SEMI, L_CURLY] } else { &[L_CURLY] };
+                    p.error_expected_tokens(ErrorCode::ExpectedToken, expected);
                     return;
                 }
             }
         }
-        SEMI => {
+        SEMI if kind == STRUCT_KW => {
             p.bump();
             return;
         }
         L_CURLY => named_fields(p),
-        L_PAREN => {
+        L_PAREN if kind == STRUCT_KW => {
             pos_fields(p);
             p.expect(SEMI);
         }
+        _ if kind == STRUCT_KW => {
+            p.error_expected_tokens(ErrorCode::ExpectedToken, &[SEMI, L_CURLY, L_PAREN]);
+            return;
+        }
         _ => {
-            p.error("expected `;`, `{`, or `(`");
+            p.error_expected_tokens(ErrorCode::ExpectedToken, &[L_CURLY]);
             return;
         }
     }
@@ -62,7 +68,7 @@ pub(super) fn enum_def(p: &mut Parser) {
                 var.complete(p, ENUM_VARIANT);
             } else {
                 var.abandon(p);
-                p.err_and_bump("expected enum variant");
+                p.err_and_bump(ErrorCode::ExpectedVariant, "an enum variant");
             }
             if !p.at(R_CURLY) {
                 p.expect(COMMA);
@@ -99,7 +105,7 @@ fn named_fields(p: &mut Parser) {
             m.complete(p, NAMED_FIELD);
         } else {
             m.abandon(p);
-            p.err_and_bump("expected field declaration");
+            p.err_and_bump(ErrorCode::ExpectedField, "a field declaration");
         }
     }
 }
@@ -11,18 +11,30 @@ pub(super) fn trait_def(p: &mut Parser) {
         type_params::bounds(p);
     }
     type_params::where_clause(p);
-    p.expect(L_CURLY);
-    // test trait_item_items
-    // impl F {
-    //     type A: Clone;
-    //     const B: i32;
-    //     fn foo() {}
-    //     fn bar(&self);
-    // }
-    while !p.at(EOF) && !p.at(R_CURLY) {
+    if p.at(L_CURLY) {
+        trait_item_list(p);
+    } else {
+        p.error_expected_tokens(ErrorCode::ExpectedToken, &[L_CURLY]);
+    }
+}
+
+// test trait_item_items
+// impl F {
+//     type A: Clone;
+//     const B: i32;
+//     fn foo() {}
+//     fn bar(&self);
+// }
+pub(crate) fn trait_item_list(p: &mut Parser) {
+    assert!(p.at(L_CURLY));
+    let m = p.start();
+    p.bump();
+    let indented = block_is_indented(p);
+    while !p.at(EOF) && !p.at(R_CURLY) && !at_unclosed_block_end(p, indented) {
         item_or_macro(p, true, ItemFlavor::Trait);
     }
     p.expect(R_CURLY);
+    m.complete(p, ITEM_LIST);
 }
 
 // test impl_item
@@ -45,19 +57,30 @@ pub(super) fn impl_item(p: &mut Parser) {
         types::type_(p);
     }
     type_params::where_clause(p);
-    p.expect(L_CURLY);
+    if p.at(L_CURLY) {
+        impl_item_list(p);
+    } else {
+        p.error_expected_tokens(ErrorCode::ExpectedToken, &[L_CURLY]);
+    }
+}
 
-    // test impl_item_items
-    // impl F {
-    //     type A = i32;
-    //     const B: i32 = 92;
-    //     fn foo() {}
-    //     fn bar(&self) {}
-    // }
-    while !p.at(EOF) && !p.at(R_CURLY) {
+// test impl_item_items
+// impl F {
+//     type A = i32;
+//     const B: i32 = 92;
+//     fn foo() {}
+//     fn bar(&self) {}
+// }
+pub(crate) fn impl_item_list(p: &mut Parser) {
+    assert!(p.at(L_CURLY));
+    let m = p.start();
+    p.bump();
+    let indented = block_is_indented(p);
+    while !p.at(EOF) && !p.at(R_CURLY) && !at_unclosed_block_end(p, indented) {
         item_or_macro(p, true, ItemFlavor::Mod);
     }
     p.expect(R_CURLY);
+    m.complete(p, ITEM_LIST);
 }
 
 fn choose_type_params_over_qpath(p: &Parser) -> bool {
@@ -37,7 +37,7 @@ fn use_tree(p: &mut Parser) {
                         L_CURLY => nested_trees(p),
                         _ => {
                             // is this unreachable?
-                            p.error("expected `{` or `*`");
+                            p.error_expected_tokens(ErrorCode::ExpectedToken, &[L_CURLY, STAR]);
                         }
                     }
                 }
@@ -46,7 +46,10 @@ fn use_tree(p: &mut Parser) {
         }
         _ => {
             m.abandon(p);
-            p.err_and_bump("expected one of `*`, `::`, `{`, `self`, `super`, `indent`");
+            p.err_and_bump_msg(
+                ErrorCode::ExpectedToken,
+                "expected one of `*`, `::`, `{`, `self`, `super`, or an identifier",
+            );
             return;
         }
     }
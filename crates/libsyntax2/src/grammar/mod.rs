@@ -32,17 +32,35 @@ mod type_params;
 mod types;
 
 use {
-    parser_api::{CompletedMarker, Parser, TokenSet},
+    ErrorCode, SyntaxNodeRef,
+    parser_api::{CompletedMarker, Marker, Parser, TokenSet},
     SyntaxKind::{self, *},
 };
 
 pub(crate) fn file(p: &mut Parser) {
     let file = p.start();
-    p.eat(SHEBANG);
+    p.eat_silently(SHEBANG);
     items::mod_contents(p, false);
     file.complete(p, FILE);
 }
 
+/// Finds a production which can reparse `node` in isolation,
+/// independently of the surrounding context.
+pub(crate) fn reparser(node: SyntaxNodeRef) -> Option<fn(&mut Parser)> {
+    let res = match node.kind() {
+        BLOCK_EXPR => expressions::block,
+        TOKEN_TREE if node.first_child_or_token()?.kind() == L_CURLY => items::token_tree,
+        ITEM_LIST => match node.parent()?.kind() {
+            MODULE => items::mod_item_list,
+            TRAIT_DEF => items::trait_item_list,
+            IMPL_ITEM => items::impl_item_list,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(res)
+}
+
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum BlockLike {
@@ -129,7 +147,7 @@ fn name(p: &mut Parser) {
         p.bump();
         m.complete(p, NAME);
     } else {
-        p.err_and_bump("expected a name");
+        p.err_and_bump(ErrorCode::ExpectedName, "a name");
     }
 }
 
@@ -139,14 +157,14 @@ fn name_ref(p: &mut Parser) {
         p.bump();
         m.complete(p, NAME_REF);
     } else {
-        p.err_and_bump("expected identifier");
+        p.err_and_bump(ErrorCode::ExpectedName, "an identifier");
     }
 }
 
-fn error_block(p: &mut Parser, message: &str) {
+fn error_block(p: &mut Parser, code: ErrorCode, what: &str) {
     assert!(p.at(L_CURLY));
     let err = p.start();
-    p.error(message);
+    p.error_expected(code, what);
     p.bump();
     let mut level: u32 = 1;
     while level > 0 && !p.at(EOF) {
@@ -159,3 +177,79 @@ fn error_block(p: &mut Parser, message: &str) {
     }
     err.complete(p, ERROR);
 }
+
+/// Keywords which start a top-level item when they begin a line.
+const ITEM_LINE_FIRST: TokenSet =
+    token_set![FN_KW, STRUCT_KW, ENUM_KW, TRAIT_KW, IMPL_KW, MOD_KW];
+
+/// Error recovery for a missing `}`: a line of the block which starts
+/// in the first column with an item most likely begins the next top-level
+/// item, so the block should be closed before it instead of swallowing
+/// the rest of the file. Lines which start with `pub` or attributes, like
+/// `#[cfg(test)]`, also begin nested items and statements in sloppily
+/// indented code, so they close the block only after an empty line.
+///
+/// The rule applies only if the contents of the block are indented,
+/// `indented` is computed by `block_is_indented`.
+fn at_unclosed_block_end(p: &Parser, indented: bool) -> bool {
+    indented && at_top_level_item(p)
+}
+
+/// Should be called right after the `{`. If nothing has been typed
+/// between the `{` and the next item yet, the block counts as indented.
+fn block_is_indented(p: &Parser) -> bool {
+    match p.line_indent() {
+        Some(0) => p.after_blank_line() && at_top_level_item(p),
+        Some(_) => true,
+        None => false,
+    }
+}
+
+fn at_top_level_item(p: &Parser) -> bool {
+    if p.line_indent() != Some(0) {
+        return false;
+    }
+    ITEM_LINE_FIRST.contains(p.current()) || p.after_blank_line() && at_item_with_modifiers(p)
+}
+
+/// Looks ahead past attributes, `pub` and `unsafe` for an item keyword.
+fn at_item_with_modifiers(p: &Parser) -> bool {
+    let mut n = 0;
+    loop {
+        match p.nth(n) {
+            POUND => {
+                n += 1;
+                if p.nth(n) != L_BRACK {
+                    return false;
+                }
+                let mut level: u32 = 0;
+                loop {
+                    match p.nth(n) {
+                        L_BRACK => level += 1,
+                        R_BRACK => level -= 1,
+                        EOF => return false,
+                        _ => (),
+                    }
+                    n += 1;
+                    if level == 0 {
+                        break;
+                    }
+                }
+            }
+            PUB_KW => {
+                n += 1;
+                if p.nth(n) == L_PAREN {
+                    while p.nth(n) != R_PAREN {
+                        if p.nth(n) == EOF {
+                            return false;
+                        }
+                        n += 1;
+                    }
+                    n += 1;
+                }
+            }
+            UNSAFE_KW => n += 1,
+            kind => return ITEM_LINE_FIRST.contains(kind),
+        }
+    }
+}
@@ -58,7 +58,7 @@ fn path_segment(p: &mut Parser, mode: Mode, first: bool) {
             if is_path_start(p) {
                 types::path_type(p);
             } else {
-                p.error("expected a trait");
+                p.error_expected(ErrorCode::ExpectedType, "a trait");
             }
         }
         p.expect(R_ANGLE);
@@ -73,7 +73,7 @@ fn path_segment(p: &mut Parser, mode: Mode, first: bool) {
             }
             SELF_KW | SUPER_KW => p.bump(),
             _ => {
-                p.err_and_bump("expected identifier");
+                p.err_and_bump(ErrorCode::ExpectedName, "an identifier");
             }
         };
     }
@@ -34,9 +34,8 @@ fn atom_pat(p: &mut Parser) -> Option<CompletedMarker> {
     //         "hello" => (),
     //     }
     // }
-    match expressions::literal(p) {
-        Some(m) => return Some(m),
-        None => (),
+    if let Some(m) = literal_pat(p) {
+        return Some(m);
     }
 
     let m = match la0 {
@@ -45,13 +44,19 @@ fn atom_pat(p: &mut Parser) -> Option<CompletedMarker> {
         L_PAREN => tuple_pat(p),
         L_BRACK => slice_pat(p),
         _ => {
-            p.err_and_bump("expected pattern");
+            p.err_and_bump(ErrorCode::ExpectedPattern, "a pattern");
             return None;
         }
     };
     Some(m)
 }
 
+fn literal_pat(p: &mut Parser) -> Option<CompletedMarker> {
+    let lit = expressions::literal(p)?;
+    let m = lit.precede(p);
+    Some(m.complete(p, LITERAL_PAT))
+}
+
 // test path_part
 // fn foo() {
 //     let foo::Bar = ();
@@ -11,7 +11,7 @@ pub(super) fn type_param_list(p: &mut Parser) {
         match p.current() {
             LIFETIME => lifetime_param(p),
             IDENT => type_param(p),
-            _ => p.err_and_bump("expected type parameter"),
+            _ => p.err_and_bump(ErrorCode::ExpectedGenericParam, "a type parameter"),
         }
         if !p.at(R_ANGLE) && !p.expect(COMMA) {
             break;
@@ -121,14 +121,14 @@ fn where_predicate(p: &mut Parser) {
         if p.at(COLON) {
             lifetime_bounds(p)
         } else {
-            p.error("expected colon")
+            p.error_expected_tokens(ErrorCode::ExpectedToken, &[COLON])
         }
     } else {
         types::path_type(p);
         if p.at(COLON) {
             bounds(p);
         } else {
-            p.error("expected colon")
+            p.error_expected_tokens(ErrorCode::ExpectedToken, &[COLON])
         }
 
     }
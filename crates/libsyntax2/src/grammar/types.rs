@@ -15,7 +15,7 @@ pub(super) fn type_(p: &mut Parser) {
         L_ANGLE => path_type(p),
         _ if paths::is_path_start(p) => path_type(p),
         _ => {
-            p.err_and_bump("expected type");
+            p.err_and_bump(ErrorCode::ExpectedType, "a type");
         }
     }
 }
@@ -85,6 +85,7 @@ fn pointer_type(p: &mut Parser) {
             // test pointer_type_no_mutability
             // type T = *();
             p.error(
+                ErrorCode::ExpectedToken,
                 "expected mut or const in raw pointer type \
                  (use `*mut T` or `*const T` as appropriate)",
             );
@@ -120,7 +121,7 @@ fn array_or_slice_type(p: &mut Parser) {
         // test array_type_missing_semi
         // type T = [() 92];
         _ => {
-            p.error("expected `;` or `]`");
+            p.error_expected_tokens(ErrorCode::ExpectedToken, &[SEMI, R_BRACK]);
             SLICE_TYPE
         }
     };
@@ -164,13 +165,13 @@ fn fn_pointer_type(p: &mut Parser) {
     // type F = unsafe ();
     if !p.eat(FN_KW) {
         m.abandon(p);
-        p.error("expected `fn`");
+        p.error_expected_tokens(ErrorCode::ExpectedToken, &[FN_KW]);
         return;
     }
     if p.at(L_PAREN) {
         params::param_list_opt_patterns(p);
     } else {
-        p.error("expected parameters")
+        p.error_expected_tokens(ErrorCode::ExpectedToken, &[L_PAREN])
     }
     // test fn_pointer_type_with_ret
     // type F = fn() -> ();
@@ -15,10 +15,20 @@ pub fn is_ident_continue(c: char) -> bool {
         || (c > '\x7f' && UnicodeXID::is_xid_continue(c))
 }
 
+/// Checks if `c` has the `Pattern_White_Space` property,
+/// which is what Rust uses for whitespace.
 pub fn is_whitespace(c: char) -> bool {
-    //FIXME: use is_pattern_whitespace
-    //https://github.com/behnam/rust-unic/issues/192
-    c.is_whitespace()
+    match c {
+        '\u{0009}'...'\u{000D}' // \t, \n, vertical tab, form feed, \r
+        | '\u{0020}' // space
+        | '\u{0085}' // next line
+        | '\u{200E}' // left-to-right mark
+        | '\u{200F}' // right-to-left mark
+        | '\u{2028}' // line separator
+        | '\u{2029}' // paragraph separator
+        => true,
+        _ => false,
+    }
 }
 
 pub fn is_dec_digit(c: char) -> bool {
@@ -1,6 +1,9 @@
 use lexer::ptr::Ptr;
 
-use SyntaxKind::{self, *};
+use {
+    ErrorCode,
+    SyntaxKind::{self, *},
+};
 
 pub(crate) fn scan_shebang(ptr: &mut Ptr) -> bool {
     if ptr.next_is('!') && ptr.nnext_is('/') {
@@ -27,6 +30,7 @@ fn scan_block_comment(ptr: &mut Ptr) -> Option<SyntaxKind> {
                 ptr.bump();
                 ptr.bump();
             } else if ptr.bump().is_none() {
+                ptr.error(ErrorCode::UnterminatedBlockComment, "unterminated block comment", 2.into());
                 break;
             }
         }
@@ -2,13 +2,17 @@ mod classes;
 mod comments;
 mod numbers;
 mod ptr;
+mod relex;
 mod strings;
 
 use {
+    ErrorCode, SyntaxError,
     SyntaxKind::{self, *},
     TextUnit,
 };
 
+pub use self::relex::{relex, TokenDiff};
+
 use self::{
     classes::*,
     comments::{scan_comment, scan_shebang},
@@ -28,27 +32,34 @@ pub struct Token {
     pub len: TextUnit,
 }
 
-/// Break a string up into its component tokens
-pub fn tokenize(text: &str) -> Vec<Token> {
+/// Break a string up into its component tokens.
+///
+/// Malformed tokens, like unterminated string literals,
+/// are reported as errors in addition to the tokens.
+pub fn tokenize(text: &str) -> (Vec<Token>, Vec<SyntaxError>) {
     let mut text = text;
+    let mut offset: TextUnit = 0.into();
     let mut acc = Vec::new();
+    let mut errors = Vec::new();
     while !text.is_empty() {
-        let token = next_token(text);
+        let (token, error) = next_token(text);
         acc.push(token);
+        errors.extend(error.map(|e| e.shifted(offset)));
+        offset += token.len;
         let len: u32 = token.len.into();
         text = &text[len as usize..];
     }
-    acc
+    (acc, errors)
 }
 
 /// Get the next token from a string
-pub fn next_token(text: &str) -> Token {
+fn next_token(text: &str) -> (Token, Option<SyntaxError>) {
     assert!(!text.is_empty());
     let mut ptr = Ptr::new(text);
     let c = ptr.bump().unwrap();
     let kind = next_token_inner(c, &mut ptr);
-    let len = ptr.into_len();
-    Token { kind, len }
+    let (len, error) = ptr.finish();
+    (Token { kind, len }, error)
 }
 
 fn next_token_inner(c: char, ptr: &mut Ptr) -> SyntaxKind {
@@ -67,6 +78,22 @@ fn next_token_inner(c: char, ptr: &mut Ptr) -> SyntaxKind {
         _ => (),
     }
 
+    // Raw identifiers, like `r#match`, are never keywords.
+    if c == 'r' && ptr.next_is('#') && ptr.nnext_is_p(is_ident_start) {
+        ptr.bump();
+        ptr.bump_while(is_ident_continue);
+        let name = &ptr.current_token_text()[2..];
+        match name {
+            "_" | "self" | "super" | "crate" | "Self" => {
+                let msg = format!("`{}` cannot be a raw identifier", name);
+                let len = ptr.len();
+                ptr.error(ErrorCode::InvalidRawIdentifier, &msg, len);
+            }
+            _ => (),
+        }
+        return IDENT;
+    }
+
     let ident_start = is_ident_start(c) && !is_string_literal_start(c, ptr.next(), ptr.nnext());
     if ident_start {
         return scan_ident(c, ptr);
@@ -156,7 +183,7 @@ fn next_token_inner(c: char, ptr: &mut Ptr) -> SyntaxKind {
                 // if we find one, then this is an invalid character literal
                 if ptr.next_is('\'') {
                     ptr.bump();
-                    return CHAR; // TODO: error reporting
+                    return CHAR; // reported as too long during validation
                 }
                 LIFETIME
             } else {
@@ -182,6 +209,8 @@ fn next_token_inner(c: char, ptr: &mut Ptr) -> SyntaxKind {
         }
         _ => (),
     }
+    let len = ptr.len();
+    ptr.error(ErrorCode::UnknownToken, "unknown start of token", len);
     ERROR
 }
 
@@ -1,10 +1,11 @@
-use TextUnit;
+use {ErrorCode, SyntaxError, TextRange, TextUnit};
 
 use std::str::Chars;
 
 pub(crate) struct Ptr<'s> {
     text: &'s str,
     len: TextUnit,
+    error: Option<SyntaxError>,
 }
 
 impl<'s> Ptr<'s> {
@@ -12,13 +13,26 @@ impl<'s> Ptr<'s> {
         Ptr {
             text,
             len: 0.into(),
+            error: None,
         }
     }
 
-    pub fn into_len(self) -> TextUnit {
+    /// Returns the length of the token and the error in it, if any.
+    pub fn finish(self) -> (TextUnit, Option<SyntaxError>) {
+        (self.len, self.error)
+    }
+
+    /// The length of the text scanned so far.
+    pub fn len(&self) -> TextUnit {
         self.len
     }
 
+    /// Reports an error for the first `len` bytes of the token.
+    pub fn error(&mut self, code: ErrorCode, msg: &str, len: TextUnit) {
+        let range = TextRange::offset_len(0.into(), len);
+        self.error = Some(SyntaxError::new(code, msg.to_string(), range));
+    }
+
     pub fn next(&self) -> Option<char> {
         self.chars().next()
     }
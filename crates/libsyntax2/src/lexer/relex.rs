@@ -0,0 +1,170 @@
+use std::{cmp, ops::Range};
+
+use {
+    lexer::{next_token, Token},
+    AtomEdit, SyntaxError, TextUnit,
+};
+
+/// The lexer looks at most this many chars past the end of a token
+/// to decide where the token ends.
+const MAX_LOOKAHEAD: usize = 2;
+
+/// The change of the token stream caused by an edit: old tokens
+/// `delete` are replaced with the tokens in `insert`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenDiff {
+    /// The range of indices of the replaced old tokens.
+    pub delete: Range<usize>,
+    /// The new tokens.
+    pub insert: Vec<Token>,
+    /// The offset of the first new token in the new text.
+    pub offset: TextUnit,
+    /// Lexer errors in the new tokens, with ranges in the new text.
+    pub errors: Vec<SyntaxError>,
+}
+
+impl TokenDiff {
+    /// Updates `tokens`, which were produced for the old text.
+    pub fn apply(&self, tokens: &mut Vec<Token>) {
+        tokens.splice(self.delete.clone(), self.insert.iter().cloned());
+    }
+}
+
+/// Updates tokens of `old_text` after `edit` without retokenizing the
+/// whole text.
+///
+/// Lexing restarts at the first token which might be affected by the
+/// edit, and stops as soon as a new token ends at the boundary of an
+/// old token after the edit: the rest of the text is unchanged, so it
+/// would be lexed to the same tokens.
+pub fn relex(old_tokens: &[Token], old_text: &str, edit: &AtomEdit) -> TokenDiff {
+    let edit_start = u32::from(edit.delete.start()) as usize;
+    let edit_end = u32::from(edit.delete.end()) as usize;
+
+    // A token ending shortly before the edit might have looked into
+    // the edited text.
+    let lookahead_start = old_text[..edit_start]
+        .char_indices()
+        .rev()
+        .take(MAX_LOOKAHEAD)
+        .last()
+        .map(|(idx, _)| idx)
+        .unwrap_or(edit_start);
+    let (mut first, mut offset) = token_at(old_tokens, old_text.len(), lookahead_start);
+
+    let mut new_text = NewText {
+        buf: format!("{}{}", &old_text[offset..edit_start], edit.insert),
+        rest: &old_text[edit_end..],
+    };
+    let new_edit_end = edit_start + edit.insert.len();
+    let mut insert = Vec::new();
+    let mut errors = Vec::new();
+    // Offset of the next new token, relative to `offset`.
+    let mut pos = 0;
+    let mut last = first;
+    let mut old_offset = offset;
+    loop {
+        if pos == new_text.buf.len() && !new_text.grow() {
+            last = old_tokens.len();
+            break;
+        }
+        let (token, error) = next_token(&new_text.buf[pos..]);
+        let token_end = pos + u32::from(token.len) as usize;
+        // The token, or the chars the lexer has looked at after it,
+        // might continue past the copied part of the text.
+        if new_text.buf.len() - token_end < MAX_LOOKAHEAD * 4 && new_text.grow() {
+            continue;
+        }
+        let new_offset = offset + pos;
+        errors.extend(error.map(|e| e.shifted(TextUnit::from(new_offset as u32))));
+        insert.push(token);
+        pos = token_end;
+        let new_offset = offset + pos;
+        if new_offset < new_edit_end {
+            continue;
+        }
+        // Skip the old tokens which are covered by the new ones.
+        let target = new_offset - new_edit_end + edit_end;
+        while last < old_tokens.len() && old_offset < target {
+            old_offset += u32::from(old_tokens[last].len) as usize;
+            last += 1;
+        }
+        if old_offset == target {
+            break;
+        }
+    }
+
+    // Tokens before the edit which were relexed to themselves are
+    // not a part of the diff.
+    let mut n_same = 0;
+    while n_same < insert.len() && first + n_same < last {
+        let token = insert[n_same];
+        let end = offset + u32::from(token.len) as usize;
+        if token != old_tokens[first + n_same] || end > edit_start {
+            break;
+        }
+        offset = end;
+        n_same += 1;
+    }
+    insert.drain(..n_same);
+    first += n_same;
+    let offset = TextUnit::from(offset as u32);
+    errors.retain(|e| e.range.start() >= offset);
+
+    TokenDiff {
+        delete: first..last,
+        insert,
+        offset,
+        errors,
+    }
+}
+
+/// Finds the first token which ends after `offset`, returning its
+/// index and start. Tokens are walked from the closer end of the text.
+fn token_at(tokens: &[Token], text_len: usize, offset: usize) -> (usize, usize) {
+    let mut idx;
+    let mut start;
+    if offset < text_len / 2 {
+        idx = 0;
+        start = 0;
+        while idx < tokens.len() {
+            let end = start + u32::from(tokens[idx].len) as usize;
+            if end > offset {
+                break;
+            }
+            start = end;
+            idx += 1;
+        }
+    } else {
+        idx = tokens.len();
+        start = text_len;
+        while idx > 0 && start > offset {
+            idx -= 1;
+            start -= u32::from(tokens[idx].len) as usize;
+        }
+    }
+    (idx, start)
+}
+
+/// The new text starting from the first relexed token. The unchanged
+/// text after the edit is copied in chunks, as the lexer needs it.
+struct NewText<'a> {
+    buf: String,
+    rest: &'a str,
+}
+
+impl<'a> NewText<'a> {
+    /// Copies the next chunk of the text, returns `false` at the end.
+    fn grow(&mut self) -> bool {
+        if self.rest.is_empty() {
+            return false;
+        }
+        let mut len = cmp::min(cmp::max(self.buf.len(), 64), self.rest.len());
+        while !self.rest.is_char_boundary(len) {
+            len += 1;
+        }
+        self.buf.push_str(&self.rest[..len]);
+        self.rest = &self.rest[len..];
+        true
+    }
+}
@@ -1,11 +1,15 @@
-use SyntaxKind::{self, *};
+use {
+    ErrorCode,
+    SyntaxKind::{self, *},
+};
 
 use lexer::ptr::Ptr;
 
 pub(crate) fn is_string_literal_start(c: char, c1: Option<char>, c2: Option<char>) -> bool {
     match (c, c1, c2) {
         ('r', Some('"'), _)
-        | ('r', Some('#'), _)
+        | ('r', Some('#'), Some('"'))
+        | ('r', Some('#'), Some('#'))
         | ('b', Some('"'), _)
         | ('b', Some('\''), _)
         | ('b', Some('r'), Some('"'))
@@ -14,15 +18,27 @@ pub(crate) fn is_string_literal_start(c: char, c1: Option<char>, c2: Option<char
     }
 }
 
+/// Scans the rest of a char literal, up to and including the closing quote.
+/// Escapes are checked later, during validation.
 pub(crate) fn scan_char(ptr: &mut Ptr) {
-    if ptr.bump().is_none() {
-        return; // TODO: error reporting is upper in the stack
-    }
-    scan_char_or_byte(ptr);
-    if !ptr.next_is('\'') {
-        return; // TODO: error reporting
+    let open_len = ptr.len();
+    while let Some(c) = ptr.next() {
+        match c {
+            '\\' => {
+                ptr.bump();
+                ptr.bump();
+            }
+            '\'' => {
+                ptr.bump();
+                return;
+            }
+            '\n' => break,
+            _ => {
+                ptr.bump();
+            }
+        }
     }
-    ptr.bump();
+    ptr.error(ErrorCode::UnterminatedChar, "unterminated character literal", open_len);
 }
 
 pub(crate) fn scan_byte_char_or_string(ptr: &mut Ptr) -> SyntaxKind {
@@ -31,15 +47,15 @@ pub(crate) fn scan_byte_char_or_string(ptr: &mut Ptr) -> SyntaxKind {
     let c = ptr.bump().unwrap();
     match c {
         '\'' => {
-            scan_byte(ptr);
+            scan_char(ptr);
             BYTE
         }
         '"' => {
-            scan_byte_string(ptr);
+            scan_string(ptr);
             BYTE_STRING
         }
         'r' => {
-            scan_raw_byte_string(ptr);
+            scan_raw_string(ptr);
             RAW_BYTE_STRING
         }
         _ => unreachable!(),
@@ -47,11 +63,17 @@ pub(crate) fn scan_byte_char_or_string(ptr: &mut Ptr) -> SyntaxKind {
 }
 
 pub(crate) fn scan_string(ptr: &mut Ptr) {
+    let open_len = ptr.len();
     while let Some(c) = ptr.bump() {
-        if c == '"' {
-            return;
+        match c {
+            '\\' => {
+                ptr.bump();
+            }
+            '"' => return,
+            _ => (),
         }
     }
+    ptr.error(ErrorCode::UnterminatedString, "unterminated string literal", open_len);
 }
 
 pub(crate) fn scan_raw_string(ptr: &mut Ptr) {
@@ -61,9 +83,12 @@ pub(crate) fn scan_raw_string(ptr: &mut Ptr) {
         ptr.bump();
     }
     if !ptr.next_is('"') {
+        let len = ptr.len();
+        ptr.error(ErrorCode::UnterminatedString, "unterminated raw string literal", len);
         return;
     }
     ptr.bump();
+    let open_len = ptr.len();
 
     while let Some(c) = ptr.bump() {
         if c == '"' {
@@ -77,42 +102,5 @@ pub(crate) fn scan_raw_string(ptr: &mut Ptr) {
             }
         }
     }
-}
-
-fn scan_byte(ptr: &mut Ptr) {
-    if ptr.next_is('\'') {
-        ptr.bump();
-        return;
-    }
-    ptr.bump();
-    if ptr.next_is('\'') {
-        ptr.bump();
-        return;
-    }
-}
-
-fn scan_byte_string(ptr: &mut Ptr) {
-    while let Some(c) = ptr.bump() {
-        if c == '"' {
-            return;
-        }
-    }
-}
-
-fn scan_raw_byte_string(ptr: &mut Ptr) {
-    if !ptr.next_is('"') {
-        return;
-    }
-    ptr.bump();
-
-    while let Some(c) = ptr.bump() {
-        if c == '"' {
-            return;
-        }
-    }
-}
-
-fn scan_char_or_byte(ptr: &mut Ptr) {
-    //FIXME: deal with escape sequencies
-    ptr.bump();
+    ptr.error(ErrorCode::UnterminatedString, "unterminated raw string literal", open_len);
 }
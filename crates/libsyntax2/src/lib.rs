@@ -23,33 +23,106 @@
 extern crate itertools;
 extern crate unicode_xid;
 extern crate drop_bomb;
-extern crate parking_lot;
+extern crate once_cell;
 extern crate smol_str;
 extern crate text_unit;
+#[cfg(feature = "serde")]
+extern crate serde;
 
 pub mod algo;
 pub mod ast;
-mod lexer;
+pub mod lexer;
 #[macro_use]
 mod parser_api;
 mod grammar;
 mod parser_impl;
+mod reparsing;
+mod ptr;
+mod unescape;
+mod validation;
 
 mod syntax_kinds;
 mod yellow;
+#[cfg(feature = "serde")]
+mod serde_impls;
 /// Utilities for simple uses of the parser.
 pub mod utils;
+mod text_utils;
+
+use std::ops::DerefMut;
 
 pub use {
-    ast::{AstNode, File},
+    ast::{AstNode, AstToken, File},
     lexer::{tokenize, Token},
+    ptr::{SyntaxNodePtr, AstPtr},
     syntax_kinds::SyntaxKind,
     text_unit::{TextRange, TextUnit},
-    yellow::{SyntaxNode, SyntaxNodeRef, SyntaxRoot, TreeRoot, SyntaxError},
+    yellow::{
+        SyntaxNode, SyntaxNodeRef, SyntaxToken, SyntaxTokenRef,
+        SyntaxElement, SyntaxElementRef, SyntaxRoot, TreeRoot, SyntaxError, ErrorCode, SyntaxText,
+        GreenNode, GreenBranch, GreenInterner,
+    },
 };
 
 
 pub fn parse(text: &str) -> SyntaxNode {
-    let tokens = tokenize(&text);
-    parser_impl::parse::<yellow::GreenBuilder>(text, &tokens)
+    let (tokens, mut errors) = tokenize(&text);
+    let (green, parse_errors) = parser_impl::parse::<yellow::GreenBuilder>(text, &tokens);
+    errors.extend(parse_errors);
+    build_tree(green, errors)
+}
+
+/// Like `parse`, but shares identical tokens and small subtrees with
+/// the trees previously built using the same `interner`.
+pub fn parse_with_interner(text: &str, interner: &mut GreenInterner) -> SyntaxNode {
+    parse_with_shared_interner(text, || interner)
+}
+
+/// Like `parse_with_interner`, but calls `lock` for the interner only
+/// after the text is tokenized and parsed. This way an interner which
+/// is shared between threads is locked only while the tree is built.
+pub fn parse_with_shared_interner<I, F>(text: &str, lock: F) -> SyntaxNode
+where
+    I: DerefMut<Target = GreenInterner>,
+    F: FnOnce() -> I,
+{
+    let (tokens, mut errors) = tokenize(&text);
+    let events = parser_impl::parse_events(text, &tokens);
+    let (green, parse_errors) = {
+        let mut interner = lock();
+        let builder = yellow::GreenBuilder::with_interner(text, &mut *interner);
+        parser_impl::build(builder, &tokens, events)
+    };
+    errors.extend(parse_errors);
+    build_tree(green, errors)
+}
+
+/// Creates the tree and runs the validation on it, so that
+/// the errors are computed only once per parse.
+fn build_tree(green: GreenNode, errors: Vec<SyntaxError>) -> SyntaxNode {
+    let mut res = SyntaxNode::new_owned(SyntaxRoot::new(green, errors));
+    let validation_errors = validation::validate(res.as_ref());
+    res.add_errors(validation_errors);
+    res
+}
+
+/// A single contiguous text change: `delete` is replaced with `insert`.
+#[derive(Debug, Clone)]
+pub struct AtomEdit {
+    pub delete: TextRange,
+    pub insert: String,
+}
+
+impl AtomEdit {
+    pub fn replace(range: TextRange, replace_with: String) -> AtomEdit {
+        AtomEdit { delete: range, insert: replace_with }
+    }
+
+    pub fn delete(range: TextRange) -> AtomEdit {
+        AtomEdit::replace(range, String::new())
+    }
+
+    pub fn insert(offset: TextUnit, text: String) -> AtomEdit {
+        AtomEdit::replace(TextRange::offset_len(offset, 0.into()), text)
+    }
 }
@@ -1,6 +1,7 @@
 use {
-    parser_impl::ParserImpl,
-    SyntaxKind::{self, ERROR},
+    parser_impl::{ParserImpl, ParseError},
+    ErrorCode,
+    SyntaxKind::{self, *},
     drop_bomb::DropBomb,
 };
 
@@ -68,6 +69,17 @@ impl<'t> Parser<'t> {
         self.current() == kind
     }
 
+    /// Returns the indentation of the current token,
+    /// if it is the first one on its line.
+    pub(crate) fn line_indent(&self) -> Option<u32> {
+        self.0.line_indent()
+    }
+
+    /// Checks if there is an empty line before the current token.
+    pub(crate) fn after_blank_line(&self) -> bool {
+        self.0.after_blank_line()
+    }
+
     pub(crate) fn at_compound2(&self, c1: SyntaxKind, c2: SyntaxKind) -> bool {
         self.0.at_compound2(c1, c2)
     }
@@ -78,7 +90,12 @@ impl<'t> Parser<'t> {
 
     /// Checks if the current token is contextual keyword with text `t`.
     pub(crate) fn at_contextual_kw(&self, t: &str) -> bool {
-        self.0.at_kw(t)
+        self.nth_at_contextual_kw(0, t)
+    }
+
+    /// Checks if the nth token is contextual keyword with text `t`.
+    pub(crate) fn nth_at_contextual_kw(&self, n: u32, t: &str) -> bool {
+        self.0.nth_at_kw(n, t)
     }
 
     /// Starts a new node in the syntax tree. All nodes and tokens
@@ -110,16 +127,51 @@ impl<'t> Parser<'t> {
         self.0.bump_compound(kind, n);
     }
 
-    /// Emit error with the `message`
+    /// Emit error with the `code` and the `message`
     /// TODO: this should be much more fancy and support
     /// structured errors with spans and notes, like rustc
     /// does.
-    pub(crate) fn error<T: Into<String>>(&mut self, message: T) {
-        self.0.error(message.into())
+    pub(crate) fn error<T: Into<String>>(&mut self, code: ErrorCode, message: T) {
+        self.0.error(ParseError { code, msg: message.into(), expected: Vec::new() })
+    }
+
+    /// Emit error saying that the parser expected `what` (like
+    /// "an expression") or one of the tokens checked for at the
+    /// current position.
+    pub(crate) fn error_expected(&mut self, code: ErrorCode, what: &str) {
+        let expected = self.0.take_expected();
+        let msg = expected_message(&expected, Some(what));
+        self.0.error(ParseError { code, msg, expected })
+    }
+
+    /// Emit error saying that the parser expected one of `kinds`
+    /// or of the tokens checked for at the current position.
+    pub(crate) fn error_expected_tokens(&mut self, code: ErrorCode, kinds: &[SyntaxKind]) {
+        for &kind in kinds {
+            self.0.add_expected(kind);
+        }
+        let expected = self.0.take_expected();
+        let msg = expected_message(&expected, None);
+        self.0.error(ParseError { code, msg, expected })
     }
 
     /// Consume the next token if it is `kind`.
+    ///
+    /// If it is not, `kind` is remembered as expected at the
+    /// current position, and will be listed in the message
+    /// if the parser reports an error here.
     pub(crate) fn eat(&mut self, kind: SyntaxKind) -> bool {
+        if !self.at(kind) {
+            self.0.add_expected(kind);
+            return false;
+        }
+        self.bump();
+        true
+    }
+
+    /// Like `eat`, but doesn't remember `kind` as expected. Use it
+    /// for optional tokens which shouldn't be suggested in errors.
+    pub(crate) fn eat_silently(&mut self, kind: SyntaxKind) -> bool {
         if !self.at(kind) {
             return false;
         }
@@ -133,19 +185,55 @@ impl<'t> Parser<'t> {
         if self.eat(kind) {
             return true;
         }
-        self.error(format!("expected {:?}", kind));
+        self.error_expected_tokens(ErrorCode::ExpectedToken, &[kind]);
         false
     }
 
-    /// Create an error node and consume the next token.
-    pub(crate) fn err_and_bump(&mut self, message: &str) {
+    /// Create an error node and consume the next token,
+    /// see `error_expected`.
+    pub(crate) fn err_and_bump(&mut self, code: ErrorCode, what: &str) {
+        let m = self.start();
+        self.error_expected(code, what);
+        self.bump();
+        m.complete(self, ERROR);
+    }
+
+    /// Like `err_and_bump`, but uses `message` as is,
+    /// without listing the expected tokens.
+    pub(crate) fn err_and_bump_msg<T: Into<String>>(&mut self, code: ErrorCode, message: T) {
         let m = self.start();
-        self.error(message);
+        self.error(code, message);
         self.bump();
         m.complete(self, ERROR);
     }
 }
 
+/// Renders messages like "expected one of `;`, `}`, or an item".
+fn expected_message(tokens: &[SyntaxKind], what: Option<&str>) -> String {
+    let mut items: Vec<String> = tokens.iter().map(|&kind| describe(kind)).collect();
+    items.extend(what.map(|it| it.to_string()));
+    match items.len() {
+        0 => "unexpected token".to_string(),
+        1 => format!("expected {}", items[0]),
+        2 => format!("expected one of {} or {}", items[0], items[1]),
+        n => format!("expected one of {}, or {}", items[..n - 1].join(", "), items[n - 1]),
+    }
+}
+
+fn describe(kind: SyntaxKind) -> String {
+    if let Some(text) = kind.token_text() {
+        return format!("`{}`", text);
+    }
+    let res = match kind {
+        IDENT => "an identifier",
+        LIFETIME => "a lifetime",
+        INT_NUMBER | FLOAT_NUMBER | CHAR | BYTE | STRING | RAW_STRING
+        | BYTE_STRING | RAW_BYTE_STRING => "a literal",
+        _ => return format!("{:?}", kind),
+    };
+    res.to_string()
+}
+
 /// See `Parser::start`.
 pub(crate) struct Marker {
     pos: u32,
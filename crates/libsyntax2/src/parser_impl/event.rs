@@ -10,7 +10,7 @@
 use std::mem;
 use {
     lexer::Token,
-    parser_impl::Sink,
+    parser_impl::{Sink, ParseError},
     SyntaxKind::{self, TOMBSTONE},
 };
 
@@ -74,7 +74,7 @@ pub(crate) enum Event {
     },
 
     Error {
-        msg: String,
+        err: ParseError,
     },
 }
 
@@ -148,7 +148,7 @@ pub(super) fn process<'a, S: Sink<'a>>(builder: &mut S, tokens: &[Token], mut ev
                 }
                 builder.leaf(kind, len);
             }
-            Event::Error { msg } => builder.error(msg),
+            Event::Error { err } => builder.error(err),
         }
     }
 }
@@ -5,25 +5,42 @@ use std::ops::{Add, AddAssign};
 pub(crate) struct ParserInput<'t> {
     text: &'t str,
     start_offsets: Vec<TextUnit>,
+    line_indents: Vec<Option<u32>>,
+    after_blank_line: Vec<bool>,
     tokens: Vec<Token>, // non-whitespace tokens
 }
 
 impl<'t> ParserInput<'t> {
-    pub fn new(text: &'t str, raw_tokens: &'t [Token]) -> ParserInput<'t> {
+    /// `line_prefix` is the text between the start of the line and
+    /// the start of `text`, it's not empty if `text` is a fragment
+    /// of a file.
+    pub fn new(text: &'t str, raw_tokens: &'t [Token], line_prefix: &str) -> ParserInput<'t> {
         let mut tokens = Vec::new();
         let mut start_offsets = Vec::new();
+        let mut line_indents = Vec::new();
+        let mut after_blank_line = Vec::new();
+        let mut indent = line_indent_after(Some(0), line_prefix);
+        let mut blank = false;
         let mut len = 0.into();
         for &token in raw_tokens.iter() {
             if !token.kind.is_trivia() {
                 tokens.push(token);
                 start_offsets.push(len);
+                line_indents.push(indent);
+                after_blank_line.push(blank);
+                blank = false;
             }
+            let range = TextRange::offset_len(len, token.len);
+            blank |= completes_blank_line(indent, &text[range]);
+            indent = line_indent_after(indent, &text[range]);
             len += token.len;
         }
 
         ParserInput {
             text,
             start_offsets,
+            line_indents,
+            after_blank_line,
             tokens,
         }
     }
@@ -60,6 +77,55 @@ impl<'t> ParserInput<'t> {
         let range = TextRange::offset_len(self.start_offsets[idx], self.tokens[idx].len);
         &self.text[range]
     }
+
+    /// Indentation of the token in chars, if it is the first one on its line.
+    pub fn line_indent(&self, pos: InputPosition) -> Option<u32> {
+        let idx = pos.0 as usize;
+        if !(idx < self.tokens.len()) {
+            return None;
+        }
+        self.line_indents[idx]
+    }
+
+    /// Is there an empty line between the token and the previous one?
+    pub fn after_blank_line(&self, pos: InputPosition) -> bool {
+        let idx = pos.0 as usize;
+        if !(idx < self.tokens.len()) {
+            return false;
+        }
+        self.after_blank_line[idx]
+    }
+}
+
+/// `indent` is the indentation of the current line so far, `None` if the
+/// line contains something besides whitespace. Returns the indentation
+/// after `text` is appended to the line.
+fn line_indent_after(indent: Option<u32>, text: &str) -> Option<u32> {
+    let (indent, rest) = match text.rfind('\n') {
+        Some(idx) => (Some(0), &text[idx + 1..]),
+        None => (indent, text),
+    };
+    if rest.chars().all(|c| c == ' ' || c == '\t') {
+        indent.map(|it| it + rest.chars().count() as u32)
+    } else {
+        None
+    }
+}
+
+/// Does `text` end a line which contains only whitespace? `indent` is
+/// the same as for `line_indent_after`.
+fn completes_blank_line(indent: Option<u32>, text: &str) -> bool {
+    let is_blank = |line: &str| line.chars().all(|c| c == ' ' || c == '\t');
+    let mut lines = text.split('\n');
+    let first = lines.next().unwrap();
+    let mut prev_is_blank = indent.is_some() && is_blank(first);
+    for line in lines {
+        if prev_is_blank {
+            return true;
+        }
+        prev_is_blank = is_blank(line);
+    }
+    false
 }
 
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
@@ -1,6 +1,8 @@
 mod event;
 mod input;
 
+use std::mem;
+
 use {
     grammar,
     lexer::Token,
@@ -9,11 +11,20 @@ use {
         event::{process, Event},
         input::{InputPosition, ParserInput},
     },
-    TextUnit,
+    ErrorCode, TextUnit,
 };
 
 use SyntaxKind::{self, EOF, TOMBSTONE};
 
+/// An error reported by the grammar. The `Sink` attaches
+/// the position to it.
+#[derive(Debug)]
+pub(crate) struct ParseError {
+    pub(crate) code: ErrorCode,
+    pub(crate) msg: String,
+    pub(crate) expected: Vec<SyntaxKind>,
+}
+
 pub(crate) trait Sink<'a> {
     type Tree;
 
@@ -22,18 +33,52 @@ pub(crate) trait Sink<'a> {
     fn leaf(&mut self, kind: SyntaxKind, len: TextUnit);
     fn start_internal(&mut self, kind: SyntaxKind);
     fn finish_internal(&mut self);
-    fn error(&mut self, err: String);
+    fn error(&mut self, err: ParseError);
     fn finish(self) -> Self::Tree;
 }
 
 /// Parse a sequence of tokens into the representative node tree
 pub(crate) fn parse<'a, S: Sink<'a>>(text: &'a str, tokens: &[Token]) -> S::Tree {
+    let events = parse_events(text, tokens);
+    build(S::new(text), tokens, events)
+}
+
+/// Runs the grammar over the whole file without building a tree,
+/// see `build`.
+pub(crate) fn parse_events(text: &str, tokens: &[Token]) -> Vec<Event> {
+    let input = input::ParserInput::new(text, tokens, "");
+    let parser_impl = ParserImpl::new(&input);
+    let mut parser_api = Parser(parser_impl);
+    grammar::file(&mut parser_api);
+    parser_api.0.into_events()
+}
+
+/// Builds the tree from the `events` of `parse_events` using `sink`.
+pub(crate) fn build<'a, S: Sink<'a>>(mut sink: S, tokens: &[Token], events: Vec<Event>) -> S::Tree {
+    process(&mut sink, tokens, events);
+    sink.finish()
+}
+
+/// Parse a sequence of tokens with a specific grammar production,
+/// used to reparse fragments of a file. Unlike `grammar::file`,
+/// a production is not guaranteed to consume all of the tokens:
+/// callers must check the length of the resulting tree.
+///
+/// `line_prefix` is the text of the file between the start of the
+/// line and the fragment, so that the fragment's tokens get the same
+/// indentation as in the whole file.
+pub(crate) fn parse_with<'a, S: Sink<'a>>(
+    text: &'a str,
+    line_prefix: &str,
+    tokens: &[Token],
+    parser: fn(&mut Parser),
+) -> S::Tree {
     let events = {
-        let input = input::ParserInput::new(text, tokens);
+        let input = input::ParserInput::new(text, tokens, line_prefix);
         let parser_impl = ParserImpl::new(&input);
         let mut parser_api = Parser(parser_impl);
-        grammar::file(&mut parser_api);
-        parser_api.0.into_events()
+        parser(&mut parser_api);
+        parser_api.0.into_fragment_events()
     };
     let mut sink = S::new(text);
     process(&mut sink, tokens, events);
@@ -48,6 +93,9 @@ pub(crate) struct ParserImpl<'t> {
 
     pos: InputPosition,
     events: Vec<Event>,
+    // Tokens the grammar has checked for at the current
+    // position, used to explain errors.
+    expected: Vec<SyntaxKind>,
 }
 
 impl<'t> ParserImpl<'t> {
@@ -57,6 +105,7 @@ impl<'t> ParserImpl<'t> {
 
             pos: InputPosition::new(),
             events: Vec::new(),
+            expected: Vec::new(),
         }
     }
 
@@ -65,6 +114,12 @@ impl<'t> ParserImpl<'t> {
         self.events
     }
 
+    /// Like `into_events`, but allows some of the tokens to
+    /// remain unconsumed.
+    pub(crate) fn into_fragment_events(self) -> Vec<Event> {
+        self.events
+    }
+
     pub(super) fn at_compound2(&self, c1: SyntaxKind, c2: SyntaxKind) -> bool {
         self.inp.kind(self.pos) == c1 && self.inp.kind(self.pos + 1) == c2
             && self.inp.start(self.pos + 1) == self.inp.start(self.pos) + self.inp.len(self.pos)
@@ -80,8 +135,16 @@ impl<'t> ParserImpl<'t> {
         self.inp.kind(self.pos + n)
     }
 
-    pub(super) fn at_kw(&self, t: &str) -> bool {
-        self.inp.text(self.pos) == t
+    pub(super) fn nth_at_kw(&self, n: u32, t: &str) -> bool {
+        self.inp.text(self.pos + n) == t
+    }
+
+    pub(super) fn line_indent(&self) -> Option<u32> {
+        self.inp.line_indent(self.pos)
+    }
+
+    pub(super) fn after_blank_line(&self) -> bool {
+        self.inp.after_blank_line(self.pos)
     }
 
     pub(super) fn start(&mut self) -> u32 {
@@ -113,16 +176,29 @@ impl<'t> ParserImpl<'t> {
         self.do_bump(kind, n);
     }
 
+    pub(super) fn add_expected(&mut self, kind: SyntaxKind) {
+        if kind != EOF && !self.expected.contains(&kind) {
+            self.expected.push(kind);
+        }
+    }
+
+    /// Returns the expected tokens, forgetting them so that
+    /// cascading errors at the same position don't repeat them.
+    pub(super) fn take_expected(&mut self) -> Vec<SyntaxKind> {
+        mem::replace(&mut self.expected, Vec::new())
+    }
+
     fn do_bump(&mut self, kind: SyntaxKind, n_raw_tokens: u8) {
         self.pos += u32::from(n_raw_tokens);
+        self.expected.clear();
         self.event(Event::Token {
             kind,
             n_raw_tokens,
         });
     }
 
-    pub(super) fn error(&mut self, msg: String) {
-        self.event(Event::Error { msg })
+    pub(super) fn error(&mut self, err: ParseError) {
+        self.event(Event::Error { err })
     }
 
     pub(super) fn complete(&mut self, pos: u32, kind: SyntaxKind) {
@@ -0,0 +1,116 @@
+//! Pointers to syntax nodes which don't borrow the tree.
+//!
+//! A `SyntaxNodePtr` identifies a node by its kind and range, so it
+//! can be stored in long-lived indices and re-resolved against a
+//! (possibly different) tree of the same file later. Resolution fails
+//! with `None` if the file has changed such that there's no longer a
+//! node of this kind at this range.
+use std::{fmt, hash, marker::PhantomData};
+
+use {
+    ast::File,
+    AstNode, SyntaxKind, SyntaxNode, SyntaxNodeRef, TextRange, TreeRoot,
+    text_utils::is_subrange,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SyntaxNodePtr {
+    kind: SyntaxKind,
+    range: TextRange,
+}
+
+impl SyntaxNodePtr {
+    pub fn new(node: SyntaxNodeRef) -> SyntaxNodePtr {
+        SyntaxNodePtr {
+            kind: node.kind(),
+            range: node.range(),
+        }
+    }
+
+    pub fn kind(&self) -> SyntaxKind {
+        self.kind
+    }
+
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Finds the node this pointer points to in `file`.
+    pub fn resolve<R: TreeRoot>(&self, file: &File<R>) -> Option<SyntaxNode<R>> {
+        self.resolve_in(file.syntax().clone())
+    }
+
+    fn resolve_in<R: TreeRoot>(&self, node: SyntaxNode<R>) -> Option<SyntaxNode<R>> {
+        if node.range() == self.range && node.kind() == self.kind {
+            return Some(node);
+        }
+        let exact = node.children()
+            .find(|it| it.range() == self.range && it.kind() == self.kind);
+        if exact.is_some() {
+            return exact;
+        }
+        // Several nested nodes may share the same range, so we descend
+        // even if the range already matches. An empty range at the
+        // boundary of two children is contained in both of them.
+        node.children()
+            .filter(|it| is_subrange(it.range(), self.range))
+            .filter_map(|it| self.resolve_in(it))
+            .next()
+    }
+}
+
+/// Like `SyntaxNodePtr`, but remembers the type of the AST node.
+pub struct AstPtr<N> {
+    raw: SyntaxNodePtr,
+    _ty: PhantomData<fn() -> N>,
+}
+
+impl<N> AstPtr<N> {
+    pub fn new<R: TreeRoot>(node: &N) -> AstPtr<N>
+        where N: AstNode<R>
+    {
+        AstPtr {
+            raw: SyntaxNodePtr::new(node.syntax().as_ref()),
+            _ty: PhantomData,
+        }
+    }
+
+    pub fn syntax_node_ptr(&self) -> SyntaxNodePtr {
+        self.raw
+    }
+
+    pub fn resolve<R: TreeRoot>(&self, file: &File<R>) -> Option<N>
+        where N: AstNode<R>
+    {
+        let syntax = self.raw.resolve(file)?;
+        N::cast(syntax)
+    }
+}
+
+impl<N> Clone for AstPtr<N> {
+    fn clone(&self) -> AstPtr<N> {
+        *self
+    }
+}
+
+impl<N> Copy for AstPtr<N> {}
+
+impl<N> PartialEq for AstPtr<N> {
+    fn eq(&self, other: &AstPtr<N>) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<N> Eq for AstPtr<N> {}
+
+impl<N> hash::Hash for AstPtr<N> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
+
+impl<N> fmt::Debug for AstPtr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("AstPtr").field(&self.raw).finish()
+    }
+}
@@ -0,0 +1,232 @@
+//! Incremental reparsing.
+//!
+//! After an edit, we try to find the smallest subtree which can be
+//! reparsed in isolation: either a single token which is relexed, or
+//! a `{}`-delimited node (block, item list, token tree) which is
+//! reparsed with the corresponding grammar production. The new green
+//! subtree is then spliced into the old tree, so that all untouched
+//! green nodes are shared between the old and the new file.
+//!
+//! If there's no suitable node, the caller falls back to a full
+//! reparse.
+use {
+    algo::{find_covering_element, find_covering_node, find_leaf_at_offset, ancestors},
+    grammar,
+    lexer::{tokenize, Token},
+    parser_api::Parser,
+    parser_impl,
+    text_utils::replace_range,
+    validation::validate_subtree,
+    yellow::{GreenBuilder, GreenNode, SyntaxError},
+    AtomEdit, SyntaxNode, SyntaxNodeRef, SyntaxRoot, SyntaxTokenRef, SyntaxElementRef, SyntaxElement,
+    TextUnit, TextRange,
+    SyntaxKind::*,
+};
+
+pub(crate) fn incremental_reparse(
+    root: SyntaxNodeRef,
+    edit: &AtomEdit,
+    errors: Vec<SyntaxError>,
+) -> Option<SyntaxNode> {
+    let (element, green, new_errors) = match reparse_token(root, edit) {
+        Some((token, green, new_errors)) => (SyntaxElement::Token(token), green, new_errors),
+        None => {
+            let (node, green, new_errors) = reparse_block(root, edit)?;
+            (SyntaxElement::Node(node), green, new_errors)
+        }
+    };
+    let start = element.range().start();
+    let new_range = TextRange::offset_len(start, green.text_len());
+    let kind = element.kind();
+    let green_root = element.replace_green(green);
+    let mut new_root = SyntaxNode::new_owned(SyntaxRoot::new(green_root, Vec::new()));
+    let mut new_errors: Vec<_> = new_errors.into_iter()
+        .map(|e| e.shifted(start))
+        .collect();
+    let new_element = find_covering_element(new_root.as_ref(), new_range);
+    assert!(new_element.kind() == kind && new_element.range() == new_range);
+    new_errors.extend(validate_subtree(new_element));
+    let errors = merge_errors(errors, new_errors, element.range(), edit);
+    new_root.add_errors(errors);
+    Some(new_root)
+}
+
+fn reparse_token<'a>(
+    root: SyntaxNodeRef<'a>,
+    edit: &AtomEdit,
+) -> Option<(SyntaxTokenRef<'a>, GreenNode, Vec<SyntaxError>)> {
+    let token = match find_covering_element(root, edit.delete) {
+        SyntaxElement::Token(token) => token,
+        // Typing at the end of a token: `foo|`.
+        _ if edit.delete.is_empty() =>
+            find_leaf_at_offset(root, edit.delete.start()).left_biased()?,
+        _ => return None,
+    };
+    match token.kind() {
+        WHITESPACE | COMMENT | IDENT | STRING | RAW_STRING => (),
+        _ => return None,
+    }
+    let text = get_text_after_edit(SyntaxElement::Token(token), edit);
+    if token.kind() == IDENT && is_contextual_kw(&text) {
+        return None;
+    }
+    if !relexes_to_single_token(token, &text) {
+        return None;
+    }
+    let green = GreenNode::new_leaf(token.kind(), &text);
+    let (_, new_errors) = tokenize(&text);
+    Some((token, green, new_errors))
+}
+
+fn reparse_block<'a>(
+    root: SyntaxNodeRef<'a>,
+    edit: &AtomEdit,
+) -> Option<(SyntaxNodeRef<'a>, GreenNode, Vec<SyntaxError>)> {
+    let (node, reparser) = find_reparsable_node(root, edit.delete)?;
+    // If the old node is not closed, its errors may be reported at its
+    // end offset, where they are indistinguishable from the errors of
+    // the following nodes.
+    if node.last_child_or_token().map(|it| it.kind()) != Some(R_CURLY) {
+        return None;
+    }
+    let text = get_text_after_edit(SyntaxElement::Node(node), edit);
+    let (tokens, mut new_errors) = tokenize(&text);
+    if !is_balanced(&tokens) {
+        return None;
+    }
+    let line_prefix = line_prefix(node);
+    let (green, parse_errors) =
+        parser_impl::parse_with::<GreenBuilder>(&text, &line_prefix, &tokens, reparser);
+    new_errors.extend(parse_errors);
+    // The production must consume exactly the reparsed text, and
+    // must finish with the closing `}`, otherwise the rest of the file
+    // could have been parsed differently.
+    if green.kind() != node.kind()
+        || green.text_len() != TextUnit::of_str(&text)
+        || green.children().last().map(|it| it.kind()) != Some(R_CURLY) {
+        return None;
+    }
+    Some((node, green, new_errors))
+}
+
+/// The text between the start of the line and `node`.
+fn line_prefix(node: SyntaxNodeRef) -> String {
+    let mut chunks = Vec::new();
+    let mut token = node.first_token().and_then(|it| it.prev_token());
+    while let Some(t) = token {
+        let text = t.borrowed_text().as_str();
+        if let Some(idx) = text.rfind('\n') {
+            chunks.push(&text[idx + 1..]);
+            break;
+        }
+        chunks.push(text);
+        token = t.prev_token();
+    }
+    chunks.iter().rev().map(|it| *it).collect()
+}
+
+fn get_text_after_edit(element: SyntaxElementRef, edit: &AtomEdit) -> String {
+    let start = element.range().start();
+    let edit_range = TextRange::from_to(edit.delete.start() - start, edit.delete.end() - start);
+    replace_range(element.text().to_string(), edit_range, &edit.insert)
+}
+
+fn is_contextual_kw(text: &str) -> bool {
+    match text {
+        "auto" | "default" | "union" | "async" | "await" | "try" | "macro_rules" => true,
+        _ => false,
+    }
+}
+
+/// Checks that `text` is lexed as a single token of the same kind as
+/// `token`, and that it doesn't glue with the neighboring tokens.
+fn relexes_to_single_token(token: SyntaxTokenRef, text: &str) -> bool {
+    let prev = token.prev_token();
+    let next = token.next_token();
+    let prev = prev.as_ref().map(|it| it.text().as_str()).unwrap_or("");
+    let next = next.as_ref().map(|it| it.text().as_str()).unwrap_or("");
+
+    let (tokens, _) = tokenize(&format!("{}{}{}", prev, text, next));
+    let start = TextUnit::of_str(prev);
+    let end = start + TextUnit::of_str(text);
+    let mut offset: TextUnit = 0.into();
+    for t in tokens.iter() {
+        if offset == start {
+            return t.kind == token.kind() && offset + t.len == end;
+        }
+        if offset > start {
+            return false;
+        }
+        offset += t.len;
+    }
+    false
+}
+
+fn find_reparsable_node(
+    root: SyntaxNodeRef,
+    range: TextRange,
+) -> Option<(SyntaxNodeRef, fn(&mut Parser))> {
+    let node = find_covering_node(root, range);
+    ancestors(node)
+        .filter_map(|node| grammar::reparser(node).map(|r| (node, r)))
+        .next()
+}
+
+fn is_balanced(tokens: &[Token]) -> bool {
+    if tokens.is_empty()
+        || tokens.first().unwrap().kind != L_CURLY
+        || tokens.last().unwrap().kind != R_CURLY {
+        return false;
+    }
+    let mut balance = 0usize;
+    for (i, t) in tokens.iter().enumerate() {
+        match t.kind {
+            L_CURLY => balance += 1,
+            R_CURLY => {
+                balance -= 1;
+                // The first `{` must be closed by the last `}`.
+                if balance == 0 && i != tokens.len() - 1 {
+                    return false;
+                }
+            }
+            _ => (),
+        }
+    }
+    balance == 0
+}
+
+/// Errors inside the reparsed node are replaced with the new ones,
+/// errors after it are shifted. The new errors are relative to the
+/// start of the file.
+fn merge_errors(
+    old_errors: Vec<SyntaxError>,
+    new_errors: Vec<SyntaxError>,
+    old_range: TextRange,
+    edit: &AtomEdit,
+) -> Vec<SyntaxError> {
+    let mut before = Vec::new();
+    let mut after = Vec::new();
+    for e in old_errors {
+        // Parser errors are empty and may sit at the boundaries of the
+        // node, lexer errors cover a part of a token.
+        let is_inside = if e.range.is_empty() {
+            old_range.start() < e.range.start() && e.range.start() < old_range.end()
+        } else {
+            old_range.start() <= e.range.start() && e.range.end() <= old_range.end()
+        };
+        if is_inside {
+            continue;
+        }
+        if e.range.start() < old_range.end() {
+            before.push(e)
+        } else {
+            let shift = |offset| offset + TextUnit::of_str(&edit.insert) - edit.delete.len();
+            let range = TextRange::from_to(shift(e.range.start()), shift(e.range.end()));
+            after.push(SyntaxError { range, ..e })
+        }
+    }
+    let mut res = before;
+    res.extend(new_errors);
+    res.extend(after);
+    res
+}
@@ -0,0 +1,126 @@
+//! Serialization of syntax trees, enabled by the `serde` feature.
+//!
+//! A node is serialized as `{"kind": "FN_DEF", "range": [0, 10], "children": [...]}`
+//! and a token as `{"kind": "IDENT", "range": [3, 6], "text": "foo"}`.
+//! Green trees can be deserialized from the same format, ranges are ignored.
+//! Node kinds must have `children` and token kinds must have `text`.
+use std::fmt;
+
+use serde::{
+    de::{self, Deserialize, Deserializer, MapAccess, Visitor},
+    ser::{Serialize, SerializeStruct, Serializer},
+};
+
+use {GreenNode, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken, TextRange, TreeRoot};
+
+impl Serialize for SyntaxKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.info().name)
+    }
+}
+
+impl<'de> Deserialize<'de> for SyntaxKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<SyntaxKind, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        SyntaxKind::from_name(&name)
+            .ok_or_else(|| de::Error::custom(format!("unknown syntax kind `{}`", name)))
+    }
+}
+
+impl<R: TreeRoot> Serialize for SyntaxNode<R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut node = serializer.serialize_struct("SyntaxNode", 3)?;
+        node.serialize_field("kind", &self.kind())?;
+        node.serialize_field("range", &range(self.range()))?;
+        node.serialize_field("children", &Children(self))?;
+        node.end()
+    }
+}
+
+impl<R: TreeRoot> Serialize for SyntaxToken<R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut token = serializer.serialize_struct("SyntaxToken", 3)?;
+        token.serialize_field("kind", &self.kind())?;
+        token.serialize_field("range", &range(self.range()))?;
+        token.serialize_field("text", self.text().as_str())?;
+        token.end()
+    }
+}
+
+impl<R: TreeRoot> Serialize for SyntaxElement<R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SyntaxElement::Node(node) => node.serialize(serializer),
+            SyntaxElement::Token(token) => token.serialize(serializer),
+        }
+    }
+}
+
+struct Children<'a, R: TreeRoot + 'a>(&'a SyntaxNode<R>);
+
+impl<'a, R: TreeRoot> Serialize for Children<'a, R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.children_with_tokens())
+    }
+}
+
+fn range(range: TextRange) -> (u32, u32) {
+    (range.start().into(), range.end().into())
+}
+
+impl<'de> Deserialize<'de> for GreenNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<GreenNode, D::Error> {
+        deserializer.deserialize_map(GreenNodeVisitor)
+    }
+}
+
+struct GreenNodeVisitor;
+
+impl<'de> Visitor<'de> for GreenNodeVisitor {
+    type Value = GreenNode;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a syntax node or token")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<GreenNode, A::Error> {
+        let mut kind: Option<SyntaxKind> = None;
+        let mut text: Option<String> = None;
+        let mut children: Option<Vec<GreenNode>> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "kind" => kind = Some(map.next_value()?),
+                "text" => text = Some(map.next_value()?),
+                "children" => children = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        let kind = kind.ok_or_else(|| de::Error::missing_field("kind"))?;
+        match (text, children) {
+            (Some(text), None) => {
+                if !kind.is_token() {
+                    let msg = format!("`{:?}` is a node kind, a node can't have `text`", kind);
+                    return Err(de::Error::custom(msg));
+                }
+                match kind.token_text() {
+                    Some(expected) if expected != text => {
+                        let msg = format!("the text of `{:?}` should be `{}`", kind, expected);
+                        Err(de::Error::custom(msg))
+                    }
+                    _ => Ok(GreenNode::new_leaf(kind, &text)),
+                }
+            }
+            (None, Some(children)) => {
+                if kind.is_token() && kind != SyntaxKind::ERROR {
+                    let msg = format!("`{:?}` is a token kind, a token can't have `children`", kind);
+                    return Err(de::Error::custom(msg));
+                }
+                Ok(GreenNode::new_branch(kind, children.into_boxed_slice()))
+            }
+            (None, None) => Err(de::Error::custom("expected either `text` or `children`")),
+            (Some(_), Some(_)) => Err(de::Error::custom("a token can't have `children`")),
+        }
+    }
+}
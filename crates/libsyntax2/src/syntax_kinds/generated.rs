@@ -89,9 +89,16 @@ pub enum SyntaxKind {
     LET_KW,
     MOVE_KW,
     RETURN_KW,
+    BREAK_KW,
+    CONTINUE_KW,
+    BOX_KW,
+    YIELD_KW,
     AUTO_KW,
     DEFAULT_KW,
     UNION_KW,
+    ASYNC_KW,
+    AWAIT_KW,
+    TRY_KW,
     ERROR,
     IDENT,
     UNDERSCORE,
@@ -110,6 +117,7 @@ pub enum SyntaxKind {
     SHEBANG,
     FILE,
     STRUCT_DEF,
+    UNION_DEF,
     ENUM_DEF,
     FN_DEF,
     EXTERN_CRATE_ITEM,
@@ -121,7 +129,10 @@ pub enum SyntaxKind {
     IMPL_ITEM,
     TYPE_DEF,
     MACRO_CALL,
+    MACRO_RULES,
+    MACRO_RULE,
     TOKEN_TREE,
+    ITEM_LIST,
     PAREN_TYPE,
     TUPLE_TYPE,
     NEVER_TYPE,
@@ -144,6 +155,7 @@ pub enum SyntaxKind {
     TUPLE_PAT,
     SLICE_PAT,
     RANGE_PAT,
+    LITERAL_PAT,
     TUPLE_EXPR,
     ARRAY_EXPR,
     PAREN_EXPR,
@@ -155,6 +167,13 @@ pub enum SyntaxKind {
     FOR_EXPR,
     BLOCK_EXPR,
     RETURN_EXPR,
+    BREAK_EXPR,
+    CONTINUE_EXPR,
+    LABEL,
+    CONDITION,
+    YIELD_EXPR,
+    ASYNC_BLOCK_EXPR,
+    TRY_BLOCK_EXPR,
     MATCH_EXPR,
     MATCH_ARM,
     MATCH_GUARD,
@@ -165,9 +184,11 @@ pub enum SyntaxKind {
     METHOD_CALL_EXPR,
     FIELD_EXPR,
     TRY_EXPR,
+    AWAIT_EXPR,
     CAST_EXPR,
     REF_EXPR,
     PREFIX_EXPR,
+    BOX_EXPR,
     RANGE_EXPR,
     BIN_EXPR,
     EXTERN_BLOCK_EXPR,
@@ -239,14 +260,230 @@ impl SyntaxKind {
             | LET_KW
             | MOVE_KW
             | RETURN_KW
+            | BREAK_KW
+            | CONTINUE_KW
+            | BOX_KW
+            | YIELD_KW
             | AUTO_KW
             | DEFAULT_KW
             | UNION_KW
+            | ASYNC_KW
+            | AWAIT_KW
+            | TRY_KW
                 => true,
             _ => false
         }
     }
 
+    /// Is this a kind of token? Note that `ERROR` is a kind of
+    /// both tokens and nodes.
+    pub fn is_token(self) -> bool {
+        match self {
+            | SEMI
+            | COMMA
+            | L_PAREN
+            | R_PAREN
+            | L_CURLY
+            | R_CURLY
+            | L_BRACK
+            | R_BRACK
+            | L_ANGLE
+            | R_ANGLE
+            | AT
+            | POUND
+            | TILDE
+            | QUESTION
+            | DOLLAR
+            | AMP
+            | PIPE
+            | PLUS
+            | STAR
+            | SLASH
+            | CARET
+            | PERCENT
+            | DOT
+            | DOTDOT
+            | DOTDOTDOT
+            | DOTDOTEQ
+            | COLON
+            | COLONCOLON
+            | EQ
+            | EQEQ
+            | FAT_ARROW
+            | EXCL
+            | NEQ
+            | MINUS
+            | THIN_ARROW
+            | LTEQ
+            | GTEQ
+            | PLUSEQ
+            | MINUSEQ
+            | AMPAMP
+            | PIPEPIPE
+            | SHL
+            | SHR
+            | SHLEQ
+            | SHREQ
+            | USE_KW
+            | FN_KW
+            | STRUCT_KW
+            | ENUM_KW
+            | TRAIT_KW
+            | IMPL_KW
+            | DYN_KW
+            | TRUE_KW
+            | FALSE_KW
+            | AS_KW
+            | EXTERN_KW
+            | CRATE_KW
+            | MOD_KW
+            | PUB_KW
+            | SELF_KW
+            | SUPER_KW
+            | IN_KW
+            | WHERE_KW
+            | FOR_KW
+            | LOOP_KW
+            | WHILE_KW
+            | IF_KW
+            | ELSE_KW
+            | MATCH_KW
+            | CONST_KW
+            | STATIC_KW
+            | MUT_KW
+            | UNSAFE_KW
+            | TYPE_KW
+            | REF_KW
+            | LET_KW
+            | MOVE_KW
+            | RETURN_KW
+            | BREAK_KW
+            | CONTINUE_KW
+            | BOX_KW
+            | YIELD_KW
+            | AUTO_KW
+            | DEFAULT_KW
+            | UNION_KW
+            | ASYNC_KW
+            | AWAIT_KW
+            | TRY_KW
+            | ERROR
+            | IDENT
+            | UNDERSCORE
+            | WHITESPACE
+            | INT_NUMBER
+            | FLOAT_NUMBER
+            | LIFETIME
+            | CHAR
+            | BYTE
+            | STRING
+            | RAW_STRING
+            | BYTE_STRING
+            | RAW_BYTE_STRING
+            | COMMENT
+            | DOC_COMMENT
+            | SHEBANG
+                => true,
+            _ => false
+        }
+    }
+
+    /// The text of a punctuation or keyword token, `None` for other kinds.
+    pub fn token_text(self) -> Option<&'static str> {
+        let text = match self {
+            SEMI => ";",
+            COMMA => ",",
+            L_PAREN => "(",
+            R_PAREN => ")",
+            L_CURLY => "{",
+            R_CURLY => "}",
+            L_BRACK => "[",
+            R_BRACK => "]",
+            L_ANGLE => "<",
+            R_ANGLE => ">",
+            AT => "@",
+            POUND => "#",
+            TILDE => "~",
+            QUESTION => "?",
+            DOLLAR => "$",
+            AMP => "&",
+            PIPE => "|",
+            PLUS => "+",
+            STAR => "*",
+            SLASH => "/",
+            CARET => "^",
+            PERCENT => "%",
+            DOT => ".",
+            DOTDOT => "..",
+            DOTDOTDOT => "...",
+            DOTDOTEQ => "..=",
+            COLON => ":",
+            COLONCOLON => "::",
+            EQ => "=",
+            EQEQ => "==",
+            FAT_ARROW => "=>",
+            EXCL => "!",
+            NEQ => "!=",
+            MINUS => "-",
+            THIN_ARROW => "->",
+            LTEQ => "<=",
+            GTEQ => ">=",
+            PLUSEQ => "+=",
+            MINUSEQ => "-=",
+            AMPAMP => "&&",
+            PIPEPIPE => "||",
+            SHL => "<<",
+            SHR => ">>",
+            SHLEQ => "<<=",
+            SHREQ => ">>=",
+            USE_KW => "use",
+            FN_KW => "fn",
+            STRUCT_KW => "struct",
+            ENUM_KW => "enum",
+            TRAIT_KW => "trait",
+            IMPL_KW => "impl",
+            DYN_KW => "dyn",
+            TRUE_KW => "true",
+            FALSE_KW => "false",
+            AS_KW => "as",
+            EXTERN_KW => "extern",
+            CRATE_KW => "crate",
+            MOD_KW => "mod",
+            PUB_KW => "pub",
+            SELF_KW => "self",
+            SUPER_KW => "super",
+            IN_KW => "in",
+            WHERE_KW => "where",
+            FOR_KW => "for",
+            LOOP_KW => "loop",
+            WHILE_KW => "while",
+            IF_KW => "if",
+            ELSE_KW => "else",
+            MATCH_KW => "match",
+            CONST_KW => "const",
+            STATIC_KW => "static",
+            MUT_KW => "mut",
+            UNSAFE_KW => "unsafe",
+            TYPE_KW => "type",
+            REF_KW => "ref",
+            LET_KW => "let",
+            MOVE_KW => "move",
+            RETURN_KW => "return",
+            BREAK_KW => "break",
+            CONTINUE_KW => "continue",
+            BOX_KW => "box",
+            YIELD_KW => "yield",
+            AUTO_KW => "auto",
+            DEFAULT_KW => "default",
+            UNION_KW => "union",
+            ASYNC_KW => "async",
+            AWAIT_KW => "await",
+            TRY_KW => "try",
+            _ => return None,
+        };
+        Some(text)
+    }
+
     pub(crate) fn info(self) -> &'static SyntaxInfo {
         match self {
             SEMI => &SyntaxInfo { name: "SEMI" },
@@ -327,9 +564,16 @@ impl SyntaxKind {
             LET_KW => &SyntaxInfo { name: "LET_KW" },
             MOVE_KW => &SyntaxInfo { name: "MOVE_KW" },
             RETURN_KW => &SyntaxInfo { name: "RETURN_KW" },
+            BREAK_KW => &SyntaxInfo { name: "BREAK_KW" },
+            CONTINUE_KW => &SyntaxInfo { name: "CONTINUE_KW" },
+            BOX_KW => &SyntaxInfo { name: "BOX_KW" },
+            YIELD_KW => &SyntaxInfo { name: "YIELD_KW" },
             AUTO_KW => &SyntaxInfo { name: "AUTO_KW" },
             DEFAULT_KW => &SyntaxInfo { name: "DEFAULT_KW" },
             UNION_KW => &SyntaxInfo { name: "UNION_KW" },
+            ASYNC_KW => &SyntaxInfo { name: "ASYNC_KW" },
+            AWAIT_KW => &SyntaxInfo { name: "AWAIT_KW" },
+            TRY_KW => &SyntaxInfo { name: "TRY_KW" },
             ERROR => &SyntaxInfo { name: "ERROR" },
             IDENT => &SyntaxInfo { name: "IDENT" },
             UNDERSCORE => &SyntaxInfo { name: "UNDERSCORE" },
@@ -348,6 +592,7 @@ impl SyntaxKind {
             SHEBANG => &SyntaxInfo { name: "SHEBANG" },
             FILE => &SyntaxInfo { name: "FILE" },
             STRUCT_DEF => &SyntaxInfo { name: "STRUCT_DEF" },
+            UNION_DEF => &SyntaxInfo { name: "UNION_DEF" },
             ENUM_DEF => &SyntaxInfo { name: "ENUM_DEF" },
             FN_DEF => &SyntaxInfo { name: "FN_DEF" },
             EXTERN_CRATE_ITEM => &SyntaxInfo { name: "EXTERN_CRATE_ITEM" },
@@ -359,7 +604,10 @@ impl SyntaxKind {
             IMPL_ITEM => &SyntaxInfo { name: "IMPL_ITEM" },
             TYPE_DEF => &SyntaxInfo { name: "TYPE_DEF" },
             MACRO_CALL => &SyntaxInfo { name: "MACRO_CALL" },
+            MACRO_RULES => &SyntaxInfo { name: "MACRO_RULES" },
+            MACRO_RULE => &SyntaxInfo { name: "MACRO_RULE" },
             TOKEN_TREE => &SyntaxInfo { name: "TOKEN_TREE" },
+            ITEM_LIST => &SyntaxInfo { name: "ITEM_LIST" },
             PAREN_TYPE => &SyntaxInfo { name: "PAREN_TYPE" },
             TUPLE_TYPE => &SyntaxInfo { name: "TUPLE_TYPE" },
             NEVER_TYPE => &SyntaxInfo { name: "NEVER_TYPE" },
@@ -382,6 +630,7 @@ impl SyntaxKind {
             TUPLE_PAT => &SyntaxInfo { name: "TUPLE_PAT" },
             SLICE_PAT => &SyntaxInfo { name: "SLICE_PAT" },
             RANGE_PAT => &SyntaxInfo { name: "RANGE_PAT" },
+            LITERAL_PAT => &SyntaxInfo { name: "LITERAL_PAT" },
             TUPLE_EXPR => &SyntaxInfo { name: "TUPLE_EXPR" },
             ARRAY_EXPR => &SyntaxInfo { name: "ARRAY_EXPR" },
             PAREN_EXPR => &SyntaxInfo { name: "PAREN_EXPR" },
@@ -393,6 +642,13 @@ impl SyntaxKind {
             FOR_EXPR => &SyntaxInfo { name: "FOR_EXPR" },
             BLOCK_EXPR => &SyntaxInfo { name: "BLOCK_EXPR" },
             RETURN_EXPR => &SyntaxInfo { name: "RETURN_EXPR" },
+            BREAK_EXPR => &SyntaxInfo { name: "BREAK_EXPR" },
+            CONTINUE_EXPR => &SyntaxInfo { name: "CONTINUE_EXPR" },
+            LABEL => &SyntaxInfo { name: "LABEL" },
+            CONDITION => &SyntaxInfo { name: "CONDITION" },
+            YIELD_EXPR => &SyntaxInfo { name: "YIELD_EXPR" },
+            ASYNC_BLOCK_EXPR => &SyntaxInfo { name: "ASYNC_BLOCK_EXPR" },
+            TRY_BLOCK_EXPR => &SyntaxInfo { name: "TRY_BLOCK_EXPR" },
             MATCH_EXPR => &SyntaxInfo { name: "MATCH_EXPR" },
             MATCH_ARM => &SyntaxInfo { name: "MATCH_ARM" },
             MATCH_GUARD => &SyntaxInfo { name: "MATCH_GUARD" },
@@ -403,9 +659,11 @@ impl SyntaxKind {
             METHOD_CALL_EXPR => &SyntaxInfo { name: "METHOD_CALL_EXPR" },
             FIELD_EXPR => &SyntaxInfo { name: "FIELD_EXPR" },
             TRY_EXPR => &SyntaxInfo { name: "TRY_EXPR" },
+            AWAIT_EXPR => &SyntaxInfo { name: "AWAIT_EXPR" },
             CAST_EXPR => &SyntaxInfo { name: "CAST_EXPR" },
             REF_EXPR => &SyntaxInfo { name: "REF_EXPR" },
             PREFIX_EXPR => &SyntaxInfo { name: "PREFIX_EXPR" },
+            BOX_EXPR => &SyntaxInfo { name: "BOX_EXPR" },
             RANGE_EXPR => &SyntaxInfo { name: "RANGE_EXPR" },
             BIN_EXPR => &SyntaxInfo { name: "BIN_EXPR" },
             EXTERN_BLOCK_EXPR => &SyntaxInfo { name: "EXTERN_BLOCK_EXPR" },
@@ -442,6 +700,224 @@ impl SyntaxKind {
             EOF => &SyntaxInfo { name: "EOF" },
         }
     }
+
+    pub fn from_name(name: &str) -> Option<SyntaxKind> {
+        let kind = match name {
+            "SEMI" => SEMI,
+            "COMMA" => COMMA,
+            "L_PAREN" => L_PAREN,
+            "R_PAREN" => R_PAREN,
+            "L_CURLY" => L_CURLY,
+            "R_CURLY" => R_CURLY,
+            "L_BRACK" => L_BRACK,
+            "R_BRACK" => R_BRACK,
+            "L_ANGLE" => L_ANGLE,
+            "R_ANGLE" => R_ANGLE,
+            "AT" => AT,
+            "POUND" => POUND,
+            "TILDE" => TILDE,
+            "QUESTION" => QUESTION,
+            "DOLLAR" => DOLLAR,
+            "AMP" => AMP,
+            "PIPE" => PIPE,
+            "PLUS" => PLUS,
+            "STAR" => STAR,
+            "SLASH" => SLASH,
+            "CARET" => CARET,
+            "PERCENT" => PERCENT,
+            "DOT" => DOT,
+            "DOTDOT" => DOTDOT,
+            "DOTDOTDOT" => DOTDOTDOT,
+            "DOTDOTEQ" => DOTDOTEQ,
+            "COLON" => COLON,
+            "COLONCOLON" => COLONCOLON,
+            "EQ" => EQ,
+            "EQEQ" => EQEQ,
+            "FAT_ARROW" => FAT_ARROW,
+            "EXCL" => EXCL,
+            "NEQ" => NEQ,
+            "MINUS" => MINUS,
+            "THIN_ARROW" => THIN_ARROW,
+            "LTEQ" => LTEQ,
+            "GTEQ" => GTEQ,
+            "PLUSEQ" => PLUSEQ,
+            "MINUSEQ" => MINUSEQ,
+            "AMPAMP" => AMPAMP,
+            "PIPEPIPE" => PIPEPIPE,
+            "SHL" => SHL,
+            "SHR" => SHR,
+            "SHLEQ" => SHLEQ,
+            "SHREQ" => SHREQ,
+            "USE_KW" => USE_KW,
+            "FN_KW" => FN_KW,
+            "STRUCT_KW" => STRUCT_KW,
+            "ENUM_KW" => ENUM_KW,
+            "TRAIT_KW" => TRAIT_KW,
+            "IMPL_KW" => IMPL_KW,
+            "DYN_KW" => DYN_KW,
+            "TRUE_KW" => TRUE_KW,
+            "FALSE_KW" => FALSE_KW,
+            "AS_KW" => AS_KW,
+            "EXTERN_KW" => EXTERN_KW,
+            "CRATE_KW" => CRATE_KW,
+            "MOD_KW" => MOD_KW,
+            "PUB_KW" => PUB_KW,
+            "SELF_KW" => SELF_KW,
+            "SUPER_KW" => SUPER_KW,
+            "IN_KW" => IN_KW,
+            "WHERE_KW" => WHERE_KW,
+            "FOR_KW" => FOR_KW,
+            "LOOP_KW" => LOOP_KW,
+            "WHILE_KW" => WHILE_KW,
+            "IF_KW" => IF_KW,
+            "ELSE_KW" => ELSE_KW,
+            "MATCH_KW" => MATCH_KW,
+            "CONST_KW" => CONST_KW,
+            "STATIC_KW" => STATIC_KW,
+            "MUT_KW" => MUT_KW,
+            "UNSAFE_KW" => UNSAFE_KW,
+            "TYPE_KW" => TYPE_KW,
+            "REF_KW" => REF_KW,
+            "LET_KW" => LET_KW,
+            "MOVE_KW" => MOVE_KW,
+            "RETURN_KW" => RETURN_KW,
+            "BREAK_KW" => BREAK_KW,
+            "CONTINUE_KW" => CONTINUE_KW,
+            "BOX_KW" => BOX_KW,
+            "YIELD_KW" => YIELD_KW,
+            "AUTO_KW" => AUTO_KW,
+            "DEFAULT_KW" => DEFAULT_KW,
+            "UNION_KW" => UNION_KW,
+            "ASYNC_KW" => ASYNC_KW,
+            "AWAIT_KW" => AWAIT_KW,
+            "TRY_KW" => TRY_KW,
+            "ERROR" => ERROR,
+            "IDENT" => IDENT,
+            "UNDERSCORE" => UNDERSCORE,
+            "WHITESPACE" => WHITESPACE,
+            "INT_NUMBER" => INT_NUMBER,
+            "FLOAT_NUMBER" => FLOAT_NUMBER,
+            "LIFETIME" => LIFETIME,
+            "CHAR" => CHAR,
+            "BYTE" => BYTE,
+            "STRING" => STRING,
+            "RAW_STRING" => RAW_STRING,
+            "BYTE_STRING" => BYTE_STRING,
+            "RAW_BYTE_STRING" => RAW_BYTE_STRING,
+            "COMMENT" => COMMENT,
+            "DOC_COMMENT" => DOC_COMMENT,
+            "SHEBANG" => SHEBANG,
+            "FILE" => FILE,
+            "STRUCT_DEF" => STRUCT_DEF,
+            "UNION_DEF" => UNION_DEF,
+            "ENUM_DEF" => ENUM_DEF,
+            "FN_DEF" => FN_DEF,
+            "EXTERN_CRATE_ITEM" => EXTERN_CRATE_ITEM,
+            "MODULE" => MODULE,
+            "USE_ITEM" => USE_ITEM,
+            "STATIC_DEF" => STATIC_DEF,
+            "CONST_DEF" => CONST_DEF,
+            "TRAIT_DEF" => TRAIT_DEF,
+            "IMPL_ITEM" => IMPL_ITEM,
+            "TYPE_DEF" => TYPE_DEF,
+            "MACRO_CALL" => MACRO_CALL,
+            "MACRO_RULES" => MACRO_RULES,
+            "MACRO_RULE" => MACRO_RULE,
+            "TOKEN_TREE" => TOKEN_TREE,
+            "ITEM_LIST" => ITEM_LIST,
+            "PAREN_TYPE" => PAREN_TYPE,
+            "TUPLE_TYPE" => TUPLE_TYPE,
+            "NEVER_TYPE" => NEVER_TYPE,
+            "PATH_TYPE" => PATH_TYPE,
+            "POINTER_TYPE" => POINTER_TYPE,
+            "ARRAY_TYPE" => ARRAY_TYPE,
+            "SLICE_TYPE" => SLICE_TYPE,
+            "REFERENCE_TYPE" => REFERENCE_TYPE,
+            "PLACEHOLDER_TYPE" => PLACEHOLDER_TYPE,
+            "FN_POINTER_TYPE" => FN_POINTER_TYPE,
+            "FOR_TYPE" => FOR_TYPE,
+            "IMPL_TRAIT_TYPE" => IMPL_TRAIT_TYPE,
+            "DYN_TRAIT_TYPE" => DYN_TRAIT_TYPE,
+            "REF_PAT" => REF_PAT,
+            "BIND_PAT" => BIND_PAT,
+            "PLACEHOLDER_PAT" => PLACEHOLDER_PAT,
+            "PATH_PAT" => PATH_PAT,
+            "STRUCT_PAT" => STRUCT_PAT,
+            "TUPLE_STRUCT_PAT" => TUPLE_STRUCT_PAT,
+            "TUPLE_PAT" => TUPLE_PAT,
+            "SLICE_PAT" => SLICE_PAT,
+            "RANGE_PAT" => RANGE_PAT,
+            "LITERAL_PAT" => LITERAL_PAT,
+            "TUPLE_EXPR" => TUPLE_EXPR,
+            "ARRAY_EXPR" => ARRAY_EXPR,
+            "PAREN_EXPR" => PAREN_EXPR,
+            "PATH_EXPR" => PATH_EXPR,
+            "LAMBDA_EXPR" => LAMBDA_EXPR,
+            "IF_EXPR" => IF_EXPR,
+            "WHILE_EXPR" => WHILE_EXPR,
+            "LOOP_EXPR" => LOOP_EXPR,
+            "FOR_EXPR" => FOR_EXPR,
+            "BLOCK_EXPR" => BLOCK_EXPR,
+            "RETURN_EXPR" => RETURN_EXPR,
+            "BREAK_EXPR" => BREAK_EXPR,
+            "CONTINUE_EXPR" => CONTINUE_EXPR,
+            "LABEL" => LABEL,
+            "CONDITION" => CONDITION,
+            "YIELD_EXPR" => YIELD_EXPR,
+            "ASYNC_BLOCK_EXPR" => ASYNC_BLOCK_EXPR,
+            "TRY_BLOCK_EXPR" => TRY_BLOCK_EXPR,
+            "MATCH_EXPR" => MATCH_EXPR,
+            "MATCH_ARM" => MATCH_ARM,
+            "MATCH_GUARD" => MATCH_GUARD,
+            "STRUCT_LIT" => STRUCT_LIT,
+            "STRUCT_LIT_FIELD" => STRUCT_LIT_FIELD,
+            "CALL_EXPR" => CALL_EXPR,
+            "INDEX_EXPR" => INDEX_EXPR,
+            "METHOD_CALL_EXPR" => METHOD_CALL_EXPR,
+            "FIELD_EXPR" => FIELD_EXPR,
+            "TRY_EXPR" => TRY_EXPR,
+            "AWAIT_EXPR" => AWAIT_EXPR,
+            "CAST_EXPR" => CAST_EXPR,
+            "REF_EXPR" => REF_EXPR,
+            "PREFIX_EXPR" => PREFIX_EXPR,
+            "BOX_EXPR" => BOX_EXPR,
+            "RANGE_EXPR" => RANGE_EXPR,
+            "BIN_EXPR" => BIN_EXPR,
+            "EXTERN_BLOCK_EXPR" => EXTERN_BLOCK_EXPR,
+            "ENUM_VARIANT" => ENUM_VARIANT,
+            "NAMED_FIELD" => NAMED_FIELD,
+            "POS_FIELD" => POS_FIELD,
+            "ATTR" => ATTR,
+            "META_ITEM" => META_ITEM,
+            "USE_TREE" => USE_TREE,
+            "PATH" => PATH,
+            "PATH_SEGMENT" => PATH_SEGMENT,
+            "LITERAL" => LITERAL,
+            "ALIAS" => ALIAS,
+            "VISIBILITY" => VISIBILITY,
+            "WHERE_CLAUSE" => WHERE_CLAUSE,
+            "WHERE_PRED" => WHERE_PRED,
+            "ABI" => ABI,
+            "NAME" => NAME,
+            "NAME_REF" => NAME_REF,
+            "LET_STMT" => LET_STMT,
+            "EXPR_STMT" => EXPR_STMT,
+            "TYPE_PARAM_LIST" => TYPE_PARAM_LIST,
+            "LIFETIME_PARAM" => LIFETIME_PARAM,
+            "TYPE_PARAM" => TYPE_PARAM,
+            "TYPE_ARG_LIST" => TYPE_ARG_LIST,
+            "LIFETIME_ARG" => LIFETIME_ARG,
+            "TYPE_ARG" => TYPE_ARG,
+            "ASSOC_TYPE_ARG" => ASSOC_TYPE_ARG,
+            "PARAM_LIST" => PARAM_LIST,
+            "PARAM" => PARAM,
+            "SELF_PARAM" => SELF_PARAM,
+            "ARG_LIST" => ARG_LIST,
+            _ => return None,
+        };
+        Some(kind)
+    }
+
     pub(crate) fn from_keyword(ident: &str) -> Option<SyntaxKind> {
         let kw = match ident {
             "use" => USE_KW,
@@ -477,6 +953,10 @@ impl SyntaxKind {
             "let" => LET_KW,
             "move" => MOVE_KW,
             "return" => RETURN_KW,
+            "break" => BREAK_KW,
+            "continue" => CONTINUE_KW,
+            "box" => BOX_KW,
+            "yield" => YIELD_KW,
             _ => return None,
         };
         Some(kw)
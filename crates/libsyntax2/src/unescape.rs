@@ -0,0 +1,157 @@
+//! Decoding of escape sequences in string, character and byte literals.
+use std::{iter::Peekable, str::CharIndices};
+
+use {TextRange, TextUnit};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Mode {
+    Char,
+    Str,
+    Byte,
+    ByteStr,
+}
+
+impl Mode {
+    fn is_bytes(self) -> bool {
+        match self {
+            Mode::Byte | Mode::ByteStr => true,
+            Mode::Char | Mode::Str => false,
+        }
+    }
+
+    fn is_str(self) -> bool {
+        match self {
+            Mode::Str | Mode::ByteStr => true,
+            Mode::Char | Mode::Byte => false,
+        }
+    }
+}
+
+/// Decodes `text`, the contents of a literal without the quotes.
+///
+/// `callback` is called for each character of the value (bytes are
+/// reported as chars `<= '\u{FF}'`), or for each invalid character or
+/// escape. Ranges are relative to the start of `text`. Line
+/// continuations of strings are skipped without calling `callback`.
+pub(crate) fn unescape<F>(text: &str, mode: Mode, callback: &mut F)
+    where F: FnMut(TextRange, Result<char, &'static str>)
+{
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let res = match c {
+            '\\' => {
+                if mode.is_str() && chars.peek().map(|&(_, c)| c) == Some('\n') {
+                    skip_whitespace(&mut chars);
+                    continue;
+                }
+                escape(&mut chars, mode)
+            }
+            c if mode.is_bytes() && !c.is_ascii() => Err("non-ASCII character in byte literal"),
+            c => Ok(c),
+        };
+        let end = chars.peek().map(|&(idx, _)| idx).unwrap_or(text.len());
+        let range = TextRange::from_to(
+            TextUnit::from(start as u32),
+            TextUnit::from(end as u32),
+        );
+        callback(range, res);
+    }
+}
+
+fn escape(chars: &mut Peekable<CharIndices>, mode: Mode) -> Result<char, &'static str> {
+    let c = match chars.next() {
+        None => return Err("unterminated escape"),
+        Some((_, c)) => c,
+    };
+    let res = match c {
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        '\\' => '\\',
+        '0' => '\0',
+        '\'' => '\'',
+        '"' => '"',
+        'x' => {
+            let hi = chars.next().and_then(|(_, c)| c.to_digit(16));
+            let lo = chars.next().and_then(|(_, c)| c.to_digit(16));
+            let value = match (hi, lo) {
+                (Some(hi), Some(lo)) => hi * 16 + lo,
+                _ => return Err("invalid `\\x` escape, expected two hex digits"),
+            };
+            if value > 0x7F && !mode.is_bytes() {
+                return Err("out of range hex escape, must be at most `\\x7F`");
+            }
+            value as u8 as char
+        }
+        'u' => {
+            if mode.is_bytes() {
+                return Err("unicode escape in byte literal");
+            }
+            unicode_escape(chars)?
+        }
+        _ => return Err("unknown character escape"),
+    };
+    Ok(res)
+}
+
+fn unicode_escape(chars: &mut Peekable<CharIndices>) -> Result<char, &'static str> {
+    match chars.next() {
+        Some((_, '{')) => (),
+        _ => return Err("invalid unicode escape, expected `{`"),
+    }
+    let mut value: u32 = 0;
+    let mut n_digits = 0;
+    loop {
+        match chars.next() {
+            Some((_, '}')) => break,
+            Some((_, '_')) => continue,
+            Some((_, c)) => match c.to_digit(16) {
+                Some(digit) if n_digits < 6 => {
+                    value = value * 16 + digit;
+                    n_digits += 1;
+                }
+                Some(_) => return Err("invalid unicode escape, at most six hex digits are allowed"),
+                None => return Err("invalid character in unicode escape"),
+            },
+            None => return Err("unterminated unicode escape"),
+        }
+    }
+    if n_digits == 0 {
+        return Err("empty unicode escape");
+    }
+    ::std::char::from_u32(value).ok_or("invalid unicode character escape")
+}
+
+fn skip_whitespace(chars: &mut Peekable<CharIndices>) {
+    while let Some(&(_, c)) = chars.peek() {
+        if !c.is_whitespace() {
+            break;
+        }
+        chars.next();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(text: &str, mode: Mode, expected: &str) {
+        let mut actual = String::new();
+        unescape(text, mode, &mut |range, res| match res {
+            Ok(c) => actual.push(c),
+            Err(msg) => actual += &format!("<{:?} {}>", range, msg),
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_unescape() {
+        check(r"a\n\x41\u{1F600}", Mode::Str, "a\nA\u{1F600}");
+        check("a\\\n    b", Mode::Str, "ab");
+        check(r"\q", Mode::Str, "<[0; 2) unknown character escape>");
+        check(r"\xFF", Mode::Char, "<[0; 4) out of range hex escape, must be at most `\\x7F`>");
+        check(r"\xFF", Mode::Byte, "\u{FF}");
+        check(r"\u{41}", Mode::ByteStr, "<[0; 2) unicode escape in byte literal>{41}");
+        check("é", Mode::Byte, "<[0; 2) non-ASCII character in byte literal>");
+    }
+}
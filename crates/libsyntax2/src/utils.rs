@@ -1,14 +1,15 @@
-use std::fmt::Write;
+use std::{collections::HashSet, fmt::Write, mem::size_of_val};
 use {
-    algo::walk::{walk, WalkEvent},
-    SyntaxNode,
+    algo::walk::{walk_with_tokens, WalkEvent},
+    yellow::GreenNode,
+    SyntaxNode, SyntaxElement, SyntaxError, TreeRoot,
 };
 
 /// Parse a file and create a string representation of the resulting parse tree.
 pub fn dump_tree(syntax: &SyntaxNode) -> String {
     let syntax = syntax.as_ref();
     let mut errors: Vec<_> = syntax.root.errors.iter().cloned().collect();
-    errors.sort_by_key(|e| e.offset);
+    errors.sort_by_key(|e| e.range.end());
     let mut err_pos = 0;
     let mut level = 0;
     let mut buf = String::new();
@@ -20,16 +21,20 @@ pub fn dump_tree(syntax: &SyntaxNode) -> String {
         };
     }
 
-    for event in walk(syntax) {
+    for event in walk_with_tokens(syntax) {
         match event {
-            WalkEvent::Enter(node) => {
+            WalkEvent::Enter(element) => {
                 indent!();
-                writeln!(buf, "{:?}", node).unwrap();
-                if node.first_child().is_none() {
-                    let off = node.range().end();
-                    while err_pos < errors.len() && errors[err_pos].offset <= off {
+                writeln!(buf, "{:?}", element).unwrap();
+                let is_leaf = match element {
+                    SyntaxElement::Node(node) => node.first_child_or_token().is_none(),
+                    SyntaxElement::Token(_) => true,
+                };
+                if is_leaf {
+                    let off = element.range().end();
+                    while err_pos < errors.len() && errors[err_pos].range.end() <= off {
                         indent!();
-                        writeln!(buf, "err: `{}`", errors[err_pos].msg).unwrap();
+                        write_error(&mut buf, &errors[err_pos]);
                         err_pos += 1;
                     }
                 }
@@ -41,8 +46,45 @@ pub fn dump_tree(syntax: &SyntaxNode) -> String {
 
     assert_eq!(level, 0);
     for err in errors[err_pos..].iter() {
-        writeln!(buf, "err: `{}`", err.msg).unwrap();
+        write_error(&mut buf, err);
     }
 
     return buf;
 }
+
+fn write_error(buf: &mut String, err: &SyntaxError) {
+    writeln!(buf, "err@{:?} {}: `{}`", err.range, err.code, err.msg).unwrap();
+}
+
+/// Measures memory used by green trees.
+///
+/// Nodes shared between trees (for example, via `GreenInterner`)
+/// are counted only once, by the first tree which contains them.
+#[derive(Debug, Default)]
+pub struct TreeMemory {
+    seen: HashSet<usize>,
+    total: usize,
+    // Keeps the measured trees alive, so that their
+    // addresses in `seen` are not reused.
+    trees: Vec<GreenNode>,
+}
+
+impl TreeMemory {
+    pub fn new() -> TreeMemory {
+        TreeMemory::default()
+    }
+
+    /// Adds the tree of `node` and returns the number of bytes
+    /// which were not already accounted for.
+    pub fn add<R: TreeRoot>(&mut self, node: &SyntaxNode<R>) -> usize {
+        let green = node.green();
+        let bytes = size_of_val(green) + green.heap_size(&mut self.seen);
+        self.total += bytes;
+        self.trees.push(green.clone());
+        bytes
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
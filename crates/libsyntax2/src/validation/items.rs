@@ -0,0 +1,67 @@
+use {
+    ast::{self, AstNode},
+    ErrorCode, SyntaxError, SyntaxNodeRef,
+    SyntaxKind::*,
+};
+
+pub(super) fn validate_node(node: SyntaxNodeRef, errors: &mut Vec<SyntaxError>) {
+    match node.kind() {
+        VISIBILITY => validate_visibility(node, errors),
+        SELF_PARAM => validate_self_param(node, errors),
+        _ => (),
+    }
+}
+
+fn validate_visibility(vis: SyntaxNodeRef, errors: &mut Vec<SyntaxError>) {
+    let item = match vis.parent() {
+        Some(it) => it,
+        None => return,
+    };
+    match item.kind() {
+        FN_DEF | TYPE_DEF | CONST_DEF => (),
+        _ => return,
+    }
+    let msg = match container(item) {
+        Some(Container::Trait) => "visibility qualifiers are not permitted on trait items",
+        Some(Container::TraitImpl) => "visibility qualifiers are not permitted on trait impl items",
+        Some(Container::InherentImpl) | None => return,
+    };
+    errors.push(SyntaxError::new(ErrorCode::VisibilityNotPermitted, msg.to_string(), vis.range()));
+}
+
+fn validate_self_param(param: SyntaxNodeRef, errors: &mut Vec<SyntaxError>) {
+    let func = match param.parent().and_then(|it| it.parent()) {
+        Some(it) => it,
+        None => return,
+    };
+    if func.kind() != FN_DEF || container(func).is_some() {
+        return;
+    }
+    errors.push(SyntaxError::new(
+        ErrorCode::InvalidSelfParam,
+        "`self` parameter is only allowed in associated functions".to_string(),
+        param.range(),
+    ));
+}
+
+enum Container {
+    Trait,
+    TraitImpl,
+    InherentImpl,
+}
+
+/// The trait or impl `item` belongs to, if any.
+fn container(item: SyntaxNodeRef) -> Option<Container> {
+    let item_list = item.parent().filter(|it| it.kind() == ITEM_LIST)?;
+    let parent = item_list.parent()?;
+    if parent.kind() == TRAIT_DEF {
+        return Some(Container::Trait);
+    }
+    let imp = ast::ImplItem::cast(parent)?;
+    let res = if imp.target_trait().is_some() {
+        Container::TraitImpl
+    } else {
+        Container::InherentImpl
+    };
+    Some(res)
+}
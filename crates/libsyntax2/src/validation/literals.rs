@@ -0,0 +1,188 @@
+use {
+    ast::{split_int, float_suffix_start},
+    unescape::{unescape, Mode},
+    ErrorCode, SyntaxError, SyntaxTokenRef, TextRange, TextUnit,
+    SyntaxKind::*,
+};
+
+pub(super) fn validate_token(token: SyntaxTokenRef, errors: &mut Vec<SyntaxError>) {
+    let text = token.borrowed_text().as_str();
+    let start = token.range().start();
+    let mut cx = Ctx { start, errors };
+    match token.kind() {
+        CHAR => validate_char(text, 0, Mode::Char, &mut cx),
+        BYTE => validate_char(text, 1, Mode::Byte, &mut cx),
+        STRING => validate_string(text, 0, Mode::Str, &mut cx),
+        BYTE_STRING => validate_string(text, 1, Mode::ByteStr, &mut cx),
+        RAW_STRING | RAW_BYTE_STRING => {
+            let quote = match text.rfind('"') {
+                Some(it) => it,
+                None => return,
+            };
+            validate_no_suffix(text, quote + 1, "string", &mut cx);
+        }
+        INT_NUMBER => validate_int(token, text, &mut cx),
+        FLOAT_NUMBER => validate_float(text, &mut cx),
+        _ => (),
+    }
+}
+
+struct Ctx<'e> {
+    start: TextUnit,
+    errors: &'e mut Vec<SyntaxError>,
+}
+
+impl<'e> Ctx<'e> {
+    /// Reports an error at `from..to`, relative to the start of the token.
+    fn error(&mut self, code: ErrorCode, from: usize, to: usize, msg: String) {
+        let range = TextRange::from_to(
+            self.start + TextUnit::from(from as u32),
+            self.start + TextUnit::from(to as u32),
+        );
+        self.errors.push(SyntaxError::new(code, msg, range));
+    }
+}
+
+/// `prefix_len` is the length of `b` for byte literals.
+fn validate_char(text: &str, prefix_len: usize, mode: Mode, cx: &mut Ctx) {
+    let open = prefix_len;
+    let close = match text.rfind('\'') {
+        Some(it) if it > open => it,
+        _ => return,
+    };
+    let (what, len_msg) = match mode {
+        Mode::Byte => ("byte", "byte literal may only contain one byte"),
+        _ => ("character", "character literal may only contain one codepoint"),
+    };
+    let mut len = 0;
+    unescape(&text[open + 1..close], mode, &mut |range, res| {
+        len += 1;
+        if let Err(msg) = res {
+            let from = open + 1 + u32::from(range.start()) as usize;
+            let to = open + 1 + u32::from(range.end()) as usize;
+            cx.error(ErrorCode::InvalidEscape, from, to, msg.to_string());
+        }
+    });
+    match len {
+        0 => cx.error(ErrorCode::EmptyCharLiteral, 0, close + 1, format!("empty {} literal", what)),
+        1 => (),
+        _ => cx.error(ErrorCode::OverlongCharLiteral, 0, close + 1, len_msg.to_string()),
+    }
+    validate_no_suffix(text, close + 1, what, cx);
+}
+
+fn validate_string(text: &str, prefix_len: usize, mode: Mode, cx: &mut Ctx) {
+    let open = prefix_len;
+    let close = match text.rfind('"') {
+        Some(it) if it > open => it,
+        _ => return,
+    };
+    unescape(&text[open + 1..close], mode, &mut |range, res| {
+        if let Err(msg) = res {
+            let from = open + 1 + u32::from(range.start()) as usize;
+            let to = open + 1 + u32::from(range.end()) as usize;
+            cx.error(ErrorCode::InvalidEscape, from, to, msg.to_string());
+        }
+    });
+    let what = if mode == Mode::ByteStr { "byte string" } else { "string" };
+    validate_no_suffix(text, close + 1, what, cx);
+}
+
+fn validate_no_suffix(text: &str, suffix_start: usize, what: &str, cx: &mut Ctx) {
+    let suffix = &text[suffix_start..];
+    if !suffix.is_empty() {
+        let msg = format!("invalid suffix `{}` for {} literal", suffix, what);
+        cx.error(ErrorCode::InvalidSuffix, suffix_start, text.len(), msg);
+    }
+}
+
+const INT_SUFFIXES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize",
+    "i8", "i16", "i32", "i64", "i128", "isize",
+];
+const FLOAT_SUFFIXES: &[&str] = &["f32", "f64"];
+
+fn validate_int(token: SyntaxTokenRef, text: &str, cx: &mut Ctx) {
+    let (radix, prefix_len, digits_end) = split_int(text);
+    let digits = &text[prefix_len..digits_end];
+    let suffix = &text[digits_end..];
+
+    let is_float_suffix = radix == 10 && FLOAT_SUFFIXES.contains(&suffix);
+    if !suffix.is_empty() && !is_float_suffix && !INT_SUFFIXES.contains(&suffix) {
+        let msg = format!("invalid suffix `{}` for integer literal", suffix);
+        cx.error(ErrorCode::InvalidSuffix, digits_end, text.len(), msg);
+        return;
+    }
+    if !digits.chars().any(|c| c != '_') {
+        let msg = "no valid digits found for number".to_string();
+        cx.error(ErrorCode::InvalidDigit, 0, text.len(), msg);
+        return;
+    }
+    for (idx, c) in digits.char_indices() {
+        if c != '_' && !c.is_digit(radix) {
+            let from = prefix_len + idx;
+            let msg = format!("invalid digit for a base {} literal", radix);
+            cx.error(ErrorCode::InvalidDigit, from, from + c.len_utf8(), msg);
+            return;
+        }
+    }
+    let value = digits.chars()
+        .filter(|&c| c != '_')
+        .try_fold(0u128, |acc, c| {
+            acc.checked_mul(radix as u128)?.checked_add(c.to_digit(radix).unwrap() as u128)
+        });
+    let value = match value {
+        Some(it) => it,
+        None => {
+            let msg = "integer literal is too large".to_string();
+            cx.error(ErrorCode::LiteralOutOfRange, 0, text.len(), msg);
+            return;
+        }
+    };
+    let max = match int_max(suffix) {
+        Some(it) => it,
+        None => return,
+    };
+    let max = if suffix.starts_with('i') && is_negated(token) { max + 1 } else { max };
+    if value > max {
+        let msg = format!("literal out of range for `{}`", suffix);
+        cx.error(ErrorCode::LiteralOutOfRange, 0, text.len(), msg);
+    }
+}
+
+/// The largest value of the integer type, `None` for types
+/// whose size depends on the target.
+fn int_max(ty: &str) -> Option<u128> {
+    let res = match ty {
+        "u8" => ::std::u8::MAX as u128,
+        "u16" => ::std::u16::MAX as u128,
+        "u32" => ::std::u32::MAX as u128,
+        "u64" => ::std::u64::MAX as u128,
+        "u128" => ::std::u128::MAX,
+        "i8" => ::std::i8::MAX as u128,
+        "i16" => ::std::i16::MAX as u128,
+        "i32" => ::std::i32::MAX as u128,
+        "i64" => ::std::i64::MAX as u128,
+        "i128" => ::std::i128::MAX as u128,
+        _ => return None,
+    };
+    Some(res)
+}
+
+/// Is the literal an operand of unary minus, like in `-128i8`?
+fn is_negated(token: SyntaxTokenRef) -> bool {
+    let prefix_expr = match token.parent().parent() {
+        Some(it) if it.kind() == PREFIX_EXPR => it,
+        _ => return false,
+    };
+    prefix_expr.first_token().map(|it| it.kind()) == Some(MINUS)
+}
+
+fn validate_float(text: &str, cx: &mut Ctx) {
+    let suffix_start = float_suffix_start(text);
+    let suffix = &text[suffix_start..];
+    if !suffix.is_empty() && !FLOAT_SUFFIXES.contains(&suffix) {
+        let msg = format!("invalid suffix `{}` for float literal", suffix);
+        cx.error(ErrorCode::InvalidSuffix, suffix_start, text.len(), msg);
+    }
+}
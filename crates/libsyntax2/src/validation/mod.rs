@@ -0,0 +1,38 @@
+//! Checks which run after parsing.
+//!
+//! The parser is deliberately permissive: it accepts a superset of
+//! Rust, so that a tree can be built for any input. The errors which
+//! are easier to detect on a complete tree, like invalid escapes in
+//! literals or `pub` on trait items, are reported from here instead.
+mod items;
+mod literals;
+
+use {
+    algo::walk::preorder_with_tokens,
+    SyntaxElement, SyntaxElementRef, SyntaxError, SyntaxNodeRef,
+};
+
+pub(crate) fn validate(root: SyntaxNodeRef) -> Vec<SyntaxError> {
+    validate_subtree(SyntaxElement::Node(root))
+}
+
+/// Validates `element` and all of its descendants.
+///
+/// The checks look only at the element itself and at its ancestors,
+/// so after an incremental reparse it's enough to validate the new
+/// subtree.
+pub(crate) fn validate_subtree(element: SyntaxElementRef) -> Vec<SyntaxError> {
+    let mut errors = Vec::new();
+    match element {
+        SyntaxElement::Node(node) => {
+            for element in preorder_with_tokens(node) {
+                match element {
+                    SyntaxElement::Node(node) => items::validate_node(node, &mut errors),
+                    SyntaxElement::Token(token) => literals::validate_token(token, &mut errors),
+                }
+            }
+        }
+        SyntaxElement::Token(token) => literals::validate_token(token, &mut errors),
+    }
+    errors
+}
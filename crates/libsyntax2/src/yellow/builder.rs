@@ -1,6 +1,6 @@
 use {
-    parser_impl::Sink,
-    yellow::{GreenNode, SyntaxError, SyntaxNode, SyntaxRoot},
+    parser_impl::{Sink, ParseError},
+    yellow::{GreenNode, GreenInterner, SyntaxError},
     SyntaxKind, TextRange, TextUnit,
 };
 
@@ -10,10 +10,19 @@ pub(crate) struct GreenBuilder<'a> {
     children: Vec<GreenNode>,
     pos: TextUnit,
     errors: Vec<SyntaxError>,
+    interner: Option<&'a mut GreenInterner>,
+}
+
+impl<'a> GreenBuilder<'a> {
+    pub(crate) fn with_interner(text: &'a str, interner: &'a mut GreenInterner) -> GreenBuilder<'a> {
+        let mut builder = GreenBuilder::new(text);
+        builder.interner = Some(interner);
+        builder
+    }
 }
 
 impl<'a> Sink<'a> for GreenBuilder<'a> {
-    type Tree = SyntaxNode;
+    type Tree = (GreenNode, Vec<SyntaxError>);
 
     fn new(text: &'a str) -> Self {
         GreenBuilder {
@@ -22,6 +31,7 @@ impl<'a> Sink<'a> for GreenBuilder<'a> {
             children: Vec::new(),
             pos: 0.into(),
             errors: Vec::new(),
+            interner: None,
         }
     }
 
@@ -29,9 +39,11 @@ impl<'a> Sink<'a> for GreenBuilder<'a> {
         let range = TextRange::offset_len(self.pos, len);
         self.pos += len;
         let text = &self.text[range];
-        self.children.push(
-            GreenNode::new_leaf(kind, text)
-        );
+        let leaf = match &mut self.interner {
+            Some(interner) => interner.leaf(kind, text),
+            None => GreenNode::new_leaf(kind, text),
+        };
+        self.children.push(leaf);
     }
 
     fn start_internal(&mut self, kind: SyntaxKind) {
@@ -44,22 +56,25 @@ impl<'a> Sink<'a> for GreenBuilder<'a> {
         let children: Vec<_> = self.children
             .drain(first_child..)
             .collect();
-        self.children.push(
-            GreenNode::new_branch(kind, children.into_boxed_slice())
-        );
+        let branch = match &mut self.interner {
+            Some(interner) => interner.branch(kind, children),
+            None => GreenNode::new_branch(kind, children.into_boxed_slice()),
+        };
+        self.children.push(branch);
     }
 
-    fn error(&mut self, message: String) {
+    fn error(&mut self, err: ParseError) {
         self.errors.push(SyntaxError {
-            msg: message,
-            offset: self.pos,
+            code: err.code,
+            msg: err.msg,
+            range: TextRange::offset_len(self.pos, 0.into()),
+            expected: err.expected,
         })
     }
 
-    fn finish(mut self) -> SyntaxNode {
+    fn finish(mut self) -> (GreenNode, Vec<SyntaxError>) {
         assert_eq!(self.children.len(), 1);
         let root = self.children.pop().unwrap();
-        let root = SyntaxRoot::new(root, self.errors);
-        SyntaxNode::new_owned(root)
+        (root, self.errors)
     }
 }
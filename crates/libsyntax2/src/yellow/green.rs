@@ -1,11 +1,13 @@
-use std::sync::Arc;
+use std::{collections::HashSet, mem::size_of, sync::Arc};
 
 use smol_str::SmolStr;
 
 use {SyntaxKind, TextUnit};
 
+/// An immutable, position-independent syntax tree, which can be
+/// freely shared between different files and versions of a file.
 #[derive(Clone, Debug)]
-pub(crate) enum GreenNode {
+pub enum GreenNode {
     Leaf {
         kind: SyntaxKind,
         text: SmolStr,
@@ -14,11 +16,11 @@ pub(crate) enum GreenNode {
 }
 
 impl GreenNode {
-    pub(crate) fn new_leaf(kind: SyntaxKind, text: &str) -> GreenNode {
+    pub fn new_leaf(kind: SyntaxKind, text: &str) -> GreenNode {
         GreenNode::Leaf { kind, text: SmolStr::new(text) }
     }
 
-    pub(crate) fn new_branch(kind: SyntaxKind, children: Box<[GreenNode]>) -> GreenNode {
+    pub fn new_branch(kind: SyntaxKind, children: Box<[GreenNode]>) -> GreenNode {
         GreenNode::Branch(Arc::new(GreenBranch::new(kind, children)))
     }
 
@@ -43,28 +45,49 @@ impl GreenNode {
         }
     }
 
-    pub fn text(&self) -> String {
-        let mut buff = String::new();
-        go(self, &mut buff);
-        return buff;
-        fn go(node: &GreenNode, buff: &mut String) {
-            match node {
-                GreenNode::Leaf { text, .. } => buff.push_str(text.as_str()),
-                GreenNode::Branch(b) => b.children().iter().for_each(|child| go(child, buff)),
+    /// The number of heap bytes used by this tree, not counting
+    /// the allocations which are already in `seen`.
+    pub(crate) fn heap_size(&self, seen: &mut HashSet<usize>) -> usize {
+        match self {
+            GreenNode::Leaf { text, .. } => {
+                if !text.is_heap_allocated() || !seen.insert(text.as_ptr() as usize) {
+                    return 0;
+                }
+                ARC_HEADER + text.len()
+            }
+            GreenNode::Branch(b) => {
+                if !seen.insert(&**b as *const GreenBranch as usize) {
+                    return 0;
+                }
+                let children = b.children();
+                ARC_HEADER
+                    + size_of::<GreenBranch>()
+                    + children.len() * size_of::<GreenNode>()
+                    + children.iter().map(|it| it.heap_size(seen)).sum::<usize>()
             }
         }
     }
 
-    pub fn leaf_text(&self) -> Option<SmolStr> {
+    pub fn is_leaf(&self) -> bool {
         match self {
-            GreenNode::Leaf { text, .. } => Some(text.clone()),
+            GreenNode::Leaf { .. } => true,
+            GreenNode::Branch(_) => false,
+        }
+    }
+
+    pub fn leaf_text(&self) -> Option<&SmolStr> {
+        match self {
+            GreenNode::Leaf { text, .. } => Some(text),
             GreenNode::Branch(_) => None,
         }
     }
 }
 
+/// Strong and weak reference counts.
+const ARC_HEADER: usize = 2 * size_of::<usize>();
+
 #[derive(Clone, Debug)]
-pub(crate) struct GreenBranch {
+pub struct GreenBranch {
     text_len: TextUnit,
     kind: SyntaxKind,
     children: Box<[GreenNode]>,
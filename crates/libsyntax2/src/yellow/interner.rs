@@ -0,0 +1,141 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use smol_str::SmolStr;
+
+use {
+    yellow::{GreenNode, green::GreenBranch},
+    SyntaxKind,
+};
+
+/// Branches with at most this many children are interned, if all
+/// of their children are interned as well. Larger subtrees are
+/// unlikely to repeat.
+const MAX_INTERNED_CHILDREN: usize = 3;
+
+/// `maybe_gc` doesn't collect interners smaller than this.
+const MIN_GC_LEN: usize = 1024;
+
+/// Deduplicates identical leaves and small subtrees of green trees.
+///
+/// The interner can be shared between several files: every tree
+/// built with it reuses the nodes created for the previous ones.
+/// Interned nodes are kept alive by the interner itself until `gc`
+/// finds that no tree uses them anymore.
+#[derive(Debug, Default)]
+pub struct GreenInterner {
+    texts: HashSet<SmolStr>,
+    branches: HashMap<(SyntaxKind, Vec<ChildKey>), GreenNode>,
+    // Addresses of the `GreenBranch`es owned by `branches`.
+    interned: HashSet<usize>,
+    len_after_gc: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum ChildKey {
+    Leaf(SyntaxKind, SmolStr),
+    Branch(usize),
+}
+
+impl GreenInterner {
+    pub fn new() -> GreenInterner {
+        GreenInterner::default()
+    }
+
+    /// The number of distinct subtrees and texts stored in the interner.
+    pub fn len(&self) -> usize {
+        self.branches.len() + self.texts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes the nodes and texts which are not used by any tree
+    /// outside of the interner, for example, the nodes of the old
+    /// versions of files.
+    pub fn gc(&mut self) {
+        // Removing a branch releases its children, which might
+        // become unused as well.
+        loop {
+            let len = self.branches.len();
+            let interned = &mut self.interned;
+            self.branches.retain(|_, node| match node {
+                GreenNode::Branch(branch) if Arc::strong_count(branch) == 1 => {
+                    interned.remove(&address(branch));
+                    false
+                }
+                _ => true,
+            });
+            if self.branches.len() == len {
+                break;
+            }
+        }
+        self.texts.retain(|text| !text.is_unique());
+        self.len_after_gc = self.len();
+    }
+
+    /// Like `gc`, but only collects if the interner has doubled in size
+    /// since the last collection, so that the cost of collecting is
+    /// amortized over the insertions.
+    pub fn maybe_gc(&mut self) {
+        if self.len() >= MIN_GC_LEN.max(2 * self.len_after_gc) {
+            self.gc();
+        }
+    }
+
+    pub(crate) fn leaf(&mut self, kind: SyntaxKind, text: &str) -> GreenNode {
+        if let Some(text) = self.texts.get(text) {
+            return GreenNode::Leaf { kind, text: text.clone() };
+        }
+        let text = SmolStr::new(text);
+        // Short texts are stored inline, there's nothing to share.
+        if text.is_heap_allocated() {
+            self.texts.insert(text.clone());
+        }
+        GreenNode::Leaf { kind, text }
+    }
+
+    pub(crate) fn branch(&mut self, kind: SyntaxKind, children: Vec<GreenNode>) -> GreenNode {
+        let key = match self.key(kind, &children) {
+            None => return GreenNode::new_branch(kind, children.into_boxed_slice()),
+            Some(key) => key,
+        };
+        if let Some(node) = self.branches.get(&key) {
+            return node.clone();
+        }
+        let node = GreenNode::new_branch(kind, children.into_boxed_slice());
+        if let GreenNode::Branch(branch) = &node {
+            self.interned.insert(address(branch));
+        }
+        self.branches.insert(key, node.clone());
+        node
+    }
+
+    fn key(&self, kind: SyntaxKind, children: &[GreenNode]) -> Option<(SyntaxKind, Vec<ChildKey>)> {
+        if children.len() > MAX_INTERNED_CHILDREN {
+            return None;
+        }
+        let mut key = Vec::with_capacity(children.len());
+        for child in children {
+            let child = match child {
+                GreenNode::Leaf { kind, text } => ChildKey::Leaf(*kind, text.clone()),
+                GreenNode::Branch(branch) => {
+                    let address = address(branch);
+                    if !self.interned.contains(&address) {
+                        return None;
+                    }
+                    ChildKey::Branch(address)
+                }
+            };
+            key.push(child);
+        }
+        Some((kind, key))
+    }
+}
+
+fn address(branch: &Arc<GreenBranch>) -> usize {
+    &**branch as *const GreenBranch as usize
+}
@@ -1,17 +1,29 @@
 mod builder;
 mod green;
+mod interner;
 mod red;
 mod syntax;
+mod syntax_error;
+mod syntax_text;
 
 use std::{
     ops::Deref,
     sync::Arc,
     ptr,
 };
-pub use self::syntax::{SyntaxNode, SyntaxNodeRef, SyntaxError};
+pub use self::syntax::{
+    SyntaxNode, SyntaxNodeRef,
+    SyntaxToken, SyntaxTokenRef,
+    SyntaxElement, SyntaxElementRef,
+};
+pub use self::{
+    syntax_error::{SyntaxError, ErrorCode},
+    green::{GreenNode, GreenBranch},
+    interner::GreenInterner,
+    syntax_text::SyntaxText,
+};
 pub(crate) use self::{
     builder::GreenBuilder,
-    green::GreenNode,
     red::RedNode,
 };
 
@@ -51,6 +63,10 @@ impl RedPtr {
     unsafe fn get<'a>(self, _root: &'a impl TreeRoot) -> &'a RedNode {
         &*self.0.as_ptr()
     }
+
+    unsafe fn get_ref<'a>(self, _root: &'a SyntaxRoot) -> &'a RedNode {
+        &*self.0.as_ptr()
+    }
 }
 
 #[test]
@@ -59,4 +75,6 @@ fn assert_send_sync() {
     f::<GreenNode>();
     f::<RedNode>();
     f::<SyntaxNode>();
+    f::<SyntaxToken>();
+    f::<GreenInterner>();
 }
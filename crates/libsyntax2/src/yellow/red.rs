@@ -1,11 +1,16 @@
-use parking_lot::RwLock;
+use once_cell::sync::OnceCell;
+
 use {yellow::{GreenNode, RedPtr}, TextUnit};
 
 #[derive(Debug)]
 pub(crate) struct RedNode {
     green: GreenNode,
     parent: Option<ParentData>,
-    children: RwLock<Box<[Option<RedNode>]>>,
+    /// Start offsets of the children, computed once when the node
+    /// is created, so that accessing a child does not sum the lengths
+    /// of its preceding siblings.
+    child_offsets: Box<[TextUnit]>,
+    children: Box<[OnceCell<RedNode>]>,
 }
 
 #[derive(Debug)]
@@ -35,15 +40,29 @@ impl RedNode {
     }
 
     fn new(green: GreenNode, parent: Option<ParentData>) -> RedNode {
-        let n_children = green.children().len();
-        let children = (0..n_children)
-            .map(|_| None)
+        let start_offset = match &parent {
+            None => 0.into(),
+            Some(p) => p.start_offset,
+        };
+        let mut offset = start_offset;
+        let child_offsets = green.children()
+            .iter()
+            .map(|child| {
+                let child_offset = offset;
+                offset += child.text_len();
+                child_offset
+            })
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+        let children = (0..child_offsets.len())
+            .map(|_| OnceCell::new())
             .collect::<Vec<_>>()
             .into_boxed_slice();
         RedNode {
             green,
             parent,
-            children: RwLock::new(children),
+            child_offsets,
+            children,
         }
     }
 
@@ -63,26 +82,11 @@ impl RedNode {
     }
 
     pub(crate) fn get_child(&self, idx: usize) -> Option<RedPtr> {
-        if idx >= self.n_children() {
-            return None;
-        }
-        match &self.children.read()[idx] {
-            Some(child) => return Some(RedPtr::new(child)),
-            None => (),
-        };
-        let green_children = self.green.children();
-        let start_offset = self.start_offset()
-            + green_children[..idx]
-            .iter()
-            .map(|x| x.text_len())
-            .sum::<TextUnit>();
-        let child =
-            RedNode::new_child(green_children[idx].clone(), RedPtr::new(self), start_offset, idx);
-        let mut children = self.children.write();
-        if children[idx].is_none() {
-            children[idx] = Some(child)
-        }
-        Some(RedPtr::new(children[idx].as_ref().unwrap()))
+        let child = self.children.get(idx)?.get_or_init(|| {
+            let green = self.green.children()[idx].clone();
+            RedNode::new_child(green, RedPtr::new(self), self.child_offsets[idx], idx)
+        });
+        Some(RedPtr::new(child))
     }
 
     pub(crate) fn parent(&self) -> Option<RedPtr> {
@@ -1,14 +1,20 @@
-use std::{fmt, sync::Arc};
+use std::{fmt, ops::Range, sync::Arc};
 
 use smol_str::SmolStr;
 
 use {
-    yellow::{RedNode, TreeRoot, SyntaxRoot, RedPtr},
+    algo::{
+        self, Direction, LeafAtOffset,
+        walk::{postorder, Preorder},
+    },
+    yellow::{GreenNode, RedNode, TreeRoot, SyntaxRoot, RedPtr, SyntaxText, SyntaxError},
     SyntaxKind::{self, *},
     TextRange, TextUnit,
 };
 
 
+/// An interior node of the syntax tree. Leaves of the tree
+/// are represented by `SyntaxToken`s.
 #[derive(Clone, Copy)]
 pub struct SyntaxNode<R: TreeRoot = Arc<SyntaxRoot>> {
     pub(crate) root: R,
@@ -30,24 +36,74 @@ impl<R: TreeRoot> Eq for SyntaxNode<R> {}
 
 pub type SyntaxNodeRef<'a> = SyntaxNode<&'a SyntaxRoot>;
 
+/// A leaf of the syntax tree: a single token with its text.
+#[derive(Clone, Copy)]
+pub struct SyntaxToken<R: TreeRoot = Arc<SyntaxRoot>> {
+    root: R,
+    red: RedPtr,
+}
+
+unsafe impl<R: TreeRoot> Send for SyntaxToken<R> {}
+unsafe impl<R: TreeRoot> Sync for SyntaxToken<R> {}
+
+impl<R1: TreeRoot, R2: TreeRoot> PartialEq<SyntaxToken<R1>> for SyntaxToken<R2> {
+    fn eq(&self, other: &SyntaxToken<R1>) -> bool {
+        self.red == other.red
+    }
+}
+
+impl<R: TreeRoot> Eq for SyntaxToken<R> {}
+
+pub type SyntaxTokenRef<'a> = SyntaxToken<&'a SyntaxRoot>;
+
+/// Either a node or a token: a child of a `SyntaxNode`.
+#[derive(Clone, Copy)]
+pub enum SyntaxElement<R: TreeRoot = Arc<SyntaxRoot>> {
+    Node(SyntaxNode<R>),
+    Token(SyntaxToken<R>),
+}
+
+impl<R1: TreeRoot, R2: TreeRoot> PartialEq<SyntaxElement<R1>> for SyntaxElement<R2> {
+    fn eq(&self, other: &SyntaxElement<R1>) -> bool {
+        match (self, other) {
+            (SyntaxElement::Node(n1), SyntaxElement::Node(n2)) => n1 == n2,
+            (SyntaxElement::Token(t1), SyntaxElement::Token(t2)) => t1 == t2,
+            _ => false,
+        }
+    }
+}
+
+impl<R: TreeRoot> Eq for SyntaxElement<R> {}
+
+pub type SyntaxElementRef<'a> = SyntaxElement<&'a SyntaxRoot>;
+
 #[test]
 fn syntax_node_ref_is_copy() {
     fn assert_copy<T: Copy>(){}
-    assert_copy::<SyntaxNodeRef>()
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
-pub struct SyntaxError {
-    pub msg: String,
-    pub offset: TextUnit,
+    assert_copy::<SyntaxNodeRef>();
+    assert_copy::<SyntaxTokenRef>();
+    assert_copy::<SyntaxElementRef>();
 }
 
 impl SyntaxNode<Arc<SyntaxRoot>> {
+    /// Creates a tree with `green` as the root node.
+    pub fn new(green: GreenNode, errors: Vec<SyntaxError>) -> Self {
+        SyntaxNode::new_owned(SyntaxRoot::new(green, errors))
+    }
+
     pub(crate) fn new_owned(root: SyntaxRoot) -> Self {
         let root = Arc::new(root);
         let red = RedPtr::new(&root.red);
         SyntaxNode { root, red }
     }
+
+    /// Appends `errors` to the errors of a freshly built tree,
+    /// keeping them sorted by position.
+    pub(crate) fn add_errors(&mut self, errors: Vec<SyntaxError>) {
+        let root = Arc::get_mut(&mut self.root).expect("the tree is already shared");
+        root.errors.extend(errors);
+        root.errors.sort_by_key(|e| e.range.start());
+    }
 }
 
 impl<R: TreeRoot> SyntaxNode<R> {
@@ -63,21 +119,11 @@ impl<R: TreeRoot> SyntaxNode<R> {
     }
 
     pub fn range(&self) -> TextRange {
-        let red = self.red();
-        TextRange::offset_len(red.start_offset(), red.green().text_len())
-    }
-
-    pub fn text(&self) -> String {
-        self.red().green().text()
+        range(self.red())
     }
 
-    pub fn children<'a>(&'a self) -> impl Iterator<Item = SyntaxNode<R>> + 'a {
-        let red = self.red();
-        let n_children = red.n_children();
-        (0..n_children).map(move |i| SyntaxNode {
-            root: self.root.clone(),
-            red: red.get_child(i).unwrap(),
-        })
+    pub fn text(&self) -> SyntaxText {
+        SyntaxText::new(self.as_ref(), self.range())
     }
 
     pub fn parent(&self) -> Option<SyntaxNode<R>> {
@@ -88,38 +134,254 @@ impl<R: TreeRoot> SyntaxNode<R> {
         })
     }
 
+    /// Child nodes of this node, tokens are skipped.
+    pub fn children<'a>(&'a self) -> impl DoubleEndedIterator<Item = SyntaxNode<R>> + 'a {
+        self.children_with_tokens().filter_map(|it| it.as_node())
+    }
+
+    pub fn children_with_tokens<'a>(&'a self) -> impl DoubleEndedIterator<Item = SyntaxElement<R>> + 'a {
+        let red = self.red();
+        let n_children = red.n_children();
+        (0..n_children).map(move |i| {
+            SyntaxElement::new(self.root.clone(), red.get_child(i).unwrap())
+        })
+    }
+
     pub fn first_child(&self) -> Option<SyntaxNode<R>> {
         self.children().next()
     }
 
+    pub fn last_child(&self) -> Option<SyntaxNode<R>> {
+        self.children().next_back()
+    }
+
+    pub fn first_child_or_token(&self) -> Option<SyntaxElement<R>> {
+        self.children_with_tokens().next()
+    }
+
+    pub fn last_child_or_token(&self) -> Option<SyntaxElement<R>> {
+        self.children_with_tokens().next_back()
+    }
+
     pub fn next_sibling(&self) -> Option<SyntaxNode<R>> {
-        let red = self.red();
-        let parent = self.parent()?;
-        let next_sibling_idx = red.index_in_parent()? + 1;
-        let sibling_red = parent.red().get_child(next_sibling_idx)?;
-        Some(SyntaxNode {
-            root: self.root.clone(),
-            red: sibling_red,
-        })
+        let mut element = self.next_sibling_or_token()?;
+        loop {
+            match element {
+                SyntaxElement::Node(node) => return Some(node),
+                SyntaxElement::Token(token) => element = token.next_sibling_or_token()?,
+            }
+        }
     }
 
     pub fn prev_sibling(&self) -> Option<SyntaxNode<R>> {
-        let red = self.red();
-        let parent = self.parent()?;
-        let prev_sibling_idx = red.index_in_parent()?.checked_sub(1)?;
-        let sibling_red = parent.red().get_child(prev_sibling_idx)?;
-        Some(SyntaxNode {
+        let mut element = self.prev_sibling_or_token()?;
+        loop {
+            match element {
+                SyntaxElement::Node(node) => return Some(node),
+                SyntaxElement::Token(token) => element = token.prev_sibling_or_token()?,
+            }
+        }
+    }
+
+    pub fn next_sibling_or_token(&self) -> Option<SyntaxElement<R>> {
+        sibling(&self.root, self.red(), 1)
+    }
+
+    pub fn prev_sibling_or_token(&self) -> Option<SyntaxElement<R>> {
+        sibling(&self.root, self.red(), -1)
+    }
+
+    /// The first token in the subtree of this node.
+    pub fn first_token(&self) -> Option<SyntaxToken<R>> {
+        self.children_with_tokens()
+            .filter_map(|it| it.first_token())
+            .next()
+    }
+
+    /// The last token in the subtree of this node.
+    pub fn last_token(&self) -> Option<SyntaxToken<R>> {
+        self.children_with_tokens()
+            .rev()
+            .filter_map(|it| it.last_token())
+            .next()
+    }
+
+    /// This node and its ancestors, up to the root.
+    pub fn ancestors(&self) -> impl Iterator<Item = SyntaxNode<R>> {
+        algo::ancestors(self.clone())
+    }
+
+    /// This node and all nodes below it, in preorder.
+    pub fn descendants(&self) -> Preorder<R> {
+        algo::descendants(self.clone())
+    }
+
+    pub fn descendants_with_tokens(&self) -> impl Iterator<Item = SyntaxElement<R>> {
+        algo::descendants_with_tokens(self.clone())
+    }
+
+    /// This node and all nodes below it, children before parents.
+    pub fn postorder(&self) -> impl Iterator<Item = SyntaxNode<R>> {
+        postorder(self.clone())
+    }
+
+    pub fn leaf_at_offset(&self, offset: TextUnit) -> LeafAtOffset<SyntaxToken<R>> {
+        algo::find_leaf_at_offset(self.clone(), offset)
+    }
+
+    /// The smallest node or token in this subtree which fully contains `range`.
+    pub fn covering_element(&self, range: TextRange) -> SyntaxElement<R> {
+        algo::find_covering_element(self.clone(), range)
+    }
+
+    /// Returns a new tree, in which this node is replaced with `green`.
+    ///
+    /// Green nodes which are not ancestors of this node are shared
+    /// between the old and the new tree. Syntax errors of the old tree
+    /// are not carried over.
+    pub fn replace_with(&self, green: GreenNode) -> SyntaxNode {
+        let root = SyntaxRoot::new(self.replace_green(green), Vec::new());
+        SyntaxNode::new_owned(root)
+    }
+
+    /// Returns a new tree, in which the children (nodes and tokens)
+    /// of this node with indices in `range` are replaced with `children`.
+    pub fn replace_children(
+        &self,
+        range: Range<usize>,
+        children: impl IntoIterator<Item = GreenNode>,
+    ) -> SyntaxNode {
+        let old_children = self.green().children();
+        let mut new_children = old_children[..range.start].to_vec();
+        new_children.extend(children);
+        new_children.extend_from_slice(&old_children[range.end..]);
+        let green = GreenNode::new_branch(self.kind(), new_children.into_boxed_slice());
+        self.replace_with(green)
+    }
+
+    /// Returns a new tree, in which `children` are inserted into this
+    /// node before the child with index `idx`.
+    pub fn insert_children(
+        &self,
+        idx: usize,
+        children: impl IntoIterator<Item = GreenNode>,
+    ) -> SyntaxNode {
+        self.replace_children(idx..idx, children)
+    }
+
+    /// Returns a new tree, in which `child` is removed from this node.
+    pub fn remove_child(&self, child: SyntaxElement<R>) -> SyntaxNode {
+        assert!(child.parent().map_or(false, |it| it == *self), "not a child: {:?}", child);
+        let idx = child.index_in_parent();
+        self.replace_children(idx..idx + 1, None)
+    }
+
+    /// Returns a green tree of the whole file, in which this node
+    /// is replaced with `green`. All other green nodes are shared.
+    pub(crate) fn replace_green(&self, green: GreenNode) -> GreenNode {
+        match self.parent() {
+            None => green,
+            Some(parent) => parent.replace_green_child(self.red().index_in_parent().unwrap(), green),
+        }
+    }
+
+    fn replace_green_child(&self, idx: usize, green: GreenNode) -> GreenNode {
+        let mut children = self.green().children().to_vec();
+        children[idx] = green;
+        let new_green = GreenNode::new_branch(self.kind(), children.into_boxed_slice());
+        self.replace_green(new_green)
+    }
+
+    pub fn green(&self) -> &GreenNode {
+        self.red().green()
+    }
+
+    fn red(&self) -> &RedNode {
+        unsafe { self.red.get(&self.root) }
+    }
+}
+
+impl<R: TreeRoot> SyntaxToken<R> {
+    pub fn as_ref<'a>(&'a self) -> SyntaxToken<&'a SyntaxRoot> {
+        SyntaxToken {
+            root: &*self.root,
+            red: self.red,
+        }
+    }
+
+    pub fn kind(&self) -> SyntaxKind {
+        self.red().green().kind()
+    }
+
+    pub fn range(&self) -> TextRange {
+        range(self.red())
+    }
+
+    pub fn text(&self) -> &SmolStr {
+        self.red().green().leaf_text().unwrap()
+    }
+
+    pub fn parent(&self) -> SyntaxNode<R> {
+        SyntaxNode {
             root: self.root.clone(),
-            red: sibling_red,
-        })
+            red: self.red().parent().unwrap(),
+        }
     }
 
-    pub fn is_leaf(&self) -> bool {
-        self.first_child().is_none()
+    pub fn next_sibling_or_token(&self) -> Option<SyntaxElement<R>> {
+        sibling(&self.root, self.red(), 1)
     }
 
-    pub fn leaf_text(&self) -> Option<SmolStr> {
-        self.red().green().leaf_text()
+    pub fn prev_sibling_or_token(&self) -> Option<SyntaxElement<R>> {
+        sibling(&self.root, self.red(), -1)
+    }
+
+    /// The token immediately following this one in the file.
+    pub fn next_token(&self) -> Option<SyntaxToken<R>> {
+        let mut element = SyntaxElement::Token(self.clone());
+        loop {
+            match element.next_sibling_or_token() {
+                Some(sibling) => {
+                    if let Some(token) = sibling.first_token() {
+                        return Some(token);
+                    }
+                    element = sibling;
+                }
+                None => element = SyntaxElement::Node(element.parent()?),
+            }
+        }
+    }
+
+    /// The token immediately preceding this one in the file.
+    pub fn prev_token(&self) -> Option<SyntaxToken<R>> {
+        let mut element = SyntaxElement::Token(self.clone());
+        loop {
+            match element.prev_sibling_or_token() {
+                Some(sibling) => {
+                    if let Some(token) = sibling.last_token() {
+                        return Some(token);
+                    }
+                    element = sibling;
+                }
+                None => element = SyntaxElement::Node(element.parent()?),
+            }
+        }
+    }
+
+    pub fn green(&self) -> &GreenNode {
+        self.red().green()
+    }
+
+    /// Returns a new tree, in which this token is replaced with `green`.
+    pub fn replace_with(&self, green: GreenNode) -> SyntaxNode {
+        let root = SyntaxRoot::new(self.replace_green(green), Vec::new());
+        SyntaxNode::new_owned(root)
+    }
+
+    /// Returns a green tree of the whole file, in which this token
+    /// is replaced with `green`. All other green nodes are shared.
+    pub(crate) fn replace_green(&self, green: GreenNode) -> GreenNode {
+        self.parent().replace_green_child(self.red().index_in_parent().unwrap(), green)
     }
 
     fn red(&self) -> &RedNode {
@@ -127,7 +389,169 @@ impl<R: TreeRoot> SyntaxNode<R> {
     }
 }
 
+impl<'a> SyntaxToken<&'a SyntaxRoot> {
+    pub(crate) fn borrowed_text(self) -> &'a SmolStr {
+        let red = unsafe { self.red.get_ref(self.root) };
+        red.green().leaf_text().unwrap()
+    }
+}
+
+impl<R: TreeRoot> SyntaxElement<R> {
+    fn new(root: R, red: RedPtr) -> SyntaxElement<R> {
+        let is_leaf = unsafe { red.get(&root) }.green().is_leaf();
+        if is_leaf {
+            SyntaxElement::Token(SyntaxToken { root, red })
+        } else {
+            SyntaxElement::Node(SyntaxNode { root, red })
+        }
+    }
+
+    pub fn as_ref<'a>(&'a self) -> SyntaxElement<&'a SyntaxRoot> {
+        match self {
+            SyntaxElement::Node(it) => SyntaxElement::Node(it.as_ref()),
+            SyntaxElement::Token(it) => SyntaxElement::Token(it.as_ref()),
+        }
+    }
+
+    pub fn kind(&self) -> SyntaxKind {
+        match self {
+            SyntaxElement::Node(it) => it.kind(),
+            SyntaxElement::Token(it) => it.kind(),
+        }
+    }
+
+    pub fn range(&self) -> TextRange {
+        match self {
+            SyntaxElement::Node(it) => it.range(),
+            SyntaxElement::Token(it) => it.range(),
+        }
+    }
+
+    pub fn text(&self) -> SyntaxText {
+        match self {
+            SyntaxElement::Node(it) => it.text(),
+            SyntaxElement::Token(it) => {
+                SyntaxText::new(it.as_ref().parent(), it.range())
+            }
+        }
+    }
+
+    pub fn parent(&self) -> Option<SyntaxNode<R>> {
+        match self {
+            SyntaxElement::Node(it) => it.parent(),
+            SyntaxElement::Token(it) => Some(it.parent()),
+        }
+    }
+
+    pub fn as_node(&self) -> Option<SyntaxNode<R>> {
+        match self {
+            SyntaxElement::Node(it) => Some(it.clone()),
+            SyntaxElement::Token(_) => None,
+        }
+    }
+
+    pub fn as_token(&self) -> Option<SyntaxToken<R>> {
+        match self {
+            SyntaxElement::Node(_) => None,
+            SyntaxElement::Token(it) => Some(it.clone()),
+        }
+    }
+
+    pub fn next_sibling_or_token(&self) -> Option<SyntaxElement<R>> {
+        match self {
+            SyntaxElement::Node(it) => it.next_sibling_or_token(),
+            SyntaxElement::Token(it) => it.next_sibling_or_token(),
+        }
+    }
+
+    pub fn prev_sibling_or_token(&self) -> Option<SyntaxElement<R>> {
+        match self {
+            SyntaxElement::Node(it) => it.prev_sibling_or_token(),
+            SyntaxElement::Token(it) => it.prev_sibling_or_token(),
+        }
+    }
+
+    pub fn non_trivia_sibling(&self, direction: Direction) -> Option<SyntaxElement<R>> {
+        algo::non_trivia_sibling(self.clone(), direction)
+    }
+
+    pub fn first_token(&self) -> Option<SyntaxToken<R>> {
+        match self {
+            SyntaxElement::Node(it) => it.first_token(),
+            SyntaxElement::Token(it) => Some(it.clone()),
+        }
+    }
+
+    pub fn last_token(&self) -> Option<SyntaxToken<R>> {
+        match self {
+            SyntaxElement::Node(it) => it.last_token(),
+            SyntaxElement::Token(it) => Some(it.clone()),
+        }
+    }
+
+    pub fn green(&self) -> &GreenNode {
+        match self {
+            SyntaxElement::Node(it) => it.green(),
+            SyntaxElement::Token(it) => it.green(),
+        }
+    }
+
+    pub fn replace_with(&self, green: GreenNode) -> SyntaxNode {
+        match self {
+            SyntaxElement::Node(it) => it.replace_with(green),
+            SyntaxElement::Token(it) => it.replace_with(green),
+        }
+    }
+
+    pub(crate) fn replace_green(&self, green: GreenNode) -> GreenNode {
+        match self {
+            SyntaxElement::Node(it) => it.replace_green(green),
+            SyntaxElement::Token(it) => it.replace_green(green),
+        }
+    }
+
+    fn index_in_parent(&self) -> usize {
+        let red = match self {
+            SyntaxElement::Node(it) => it.red(),
+            SyntaxElement::Token(it) => it.red(),
+        };
+        red.index_in_parent().unwrap()
+    }
+}
+
+impl<R: TreeRoot> From<SyntaxNode<R>> for SyntaxElement<R> {
+    fn from(node: SyntaxNode<R>) -> SyntaxElement<R> {
+        SyntaxElement::Node(node)
+    }
+}
+
+impl<R: TreeRoot> From<SyntaxToken<R>> for SyntaxElement<R> {
+    fn from(token: SyntaxToken<R>) -> SyntaxElement<R> {
+        SyntaxElement::Token(token)
+    }
+}
+
+fn range(red: &RedNode) -> TextRange {
+    TextRange::offset_len(red.start_offset(), red.green().text_len())
+}
+
+fn sibling<R: TreeRoot>(root: &R, red: &RedNode, delta: isize) -> Option<SyntaxElement<R>> {
+    let parent = unsafe { red.parent()?.get(root) };
+    let idx = red.index_in_parent()? as isize + delta;
+    if idx < 0 {
+        return None;
+    }
+    let sibling = parent.get_child(idx as usize)?;
+    Some(SyntaxElement::new(root.clone(), sibling))
+}
+
 impl<R: TreeRoot> fmt::Debug for SyntaxNode<R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{:?}@{:?}", self.kind(), self.range())
+    }
+}
+
+impl<R: TreeRoot> fmt::Debug for SyntaxToken<R> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         write!(fmt, "{:?}@{:?}", self.kind(), self.range())?;
         if has_short_text(self.kind()) {
@@ -137,6 +561,15 @@ impl<R: TreeRoot> fmt::Debug for SyntaxNode<R> {
     }
 }
 
+impl<R: TreeRoot> fmt::Debug for SyntaxElement<R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SyntaxElement::Node(it) => fmt::Debug::fmt(it, fmt),
+            SyntaxElement::Token(it) => fmt::Debug::fmt(it, fmt),
+        }
+    }
+}
+
 fn has_short_text(kind: SyntaxKind) -> bool {
     match kind {
         IDENT | LIFETIME | INT_NUMBER | FLOAT_NUMBER => true,
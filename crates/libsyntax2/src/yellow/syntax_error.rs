@@ -0,0 +1,110 @@
+use std::fmt;
+
+use {SyntaxKind, TextRange, TextUnit};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SyntaxError {
+    pub code: ErrorCode,
+    pub msg: String,
+    /// Errors from the parser have an empty range at the
+    /// position where the error was detected.
+    pub range: TextRange,
+    /// Tokens which would have been accepted instead,
+    /// empty if the error is not about a missing token.
+    pub expected: Vec<SyntaxKind>,
+}
+
+impl SyntaxError {
+    pub(crate) fn new(code: ErrorCode, msg: String, range: TextRange) -> SyntaxError {
+        SyntaxError { code, msg, range, expected: Vec::new() }
+    }
+
+    pub(crate) fn shifted(mut self, delta: TextUnit) -> SyntaxError {
+        self.range = TextRange::from_to(self.range.start() + delta, self.range.end() + delta);
+        self
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.msg)
+    }
+}
+
+/// A category of `SyntaxError`.
+///
+/// Unlike messages, codes are stable: tools can match on
+/// them, and `as_str` gives a name suitable for serialization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    // Parser errors.
+    ExpectedToken,
+    ExpectedName,
+    ExpectedItem,
+    ExpectedExpression,
+    ExpectedBlock,
+    ExpectedLoop,
+    ExpectedType,
+    ExpectedPattern,
+    ExpectedField,
+    ExpectedVariant,
+    ExpectedGenericParam,
+    ExpectedTokenTree,
+    UnmatchedBrace,
+
+    // Lexer errors.
+    UnterminatedString,
+    UnterminatedChar,
+    UnterminatedBlockComment,
+    UnknownToken,
+    InvalidRawIdentifier,
+
+    // Validation errors.
+    InvalidEscape,
+    EmptyCharLiteral,
+    OverlongCharLiteral,
+    InvalidSuffix,
+    InvalidDigit,
+    LiteralOutOfRange,
+    VisibilityNotPermitted,
+    InvalidSelfParam,
+}
+
+impl ErrorCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::ExpectedToken => "expected-token",
+            ErrorCode::ExpectedName => "expected-name",
+            ErrorCode::ExpectedItem => "expected-item",
+            ErrorCode::ExpectedExpression => "expected-expression",
+            ErrorCode::ExpectedBlock => "expected-block",
+            ErrorCode::ExpectedLoop => "expected-loop",
+            ErrorCode::ExpectedType => "expected-type",
+            ErrorCode::ExpectedPattern => "expected-pattern",
+            ErrorCode::ExpectedField => "expected-field",
+            ErrorCode::ExpectedVariant => "expected-variant",
+            ErrorCode::ExpectedGenericParam => "expected-generic-param",
+            ErrorCode::ExpectedTokenTree => "expected-token-tree",
+            ErrorCode::UnmatchedBrace => "unmatched-brace",
+            ErrorCode::UnterminatedString => "unterminated-string",
+            ErrorCode::UnterminatedChar => "unterminated-char",
+            ErrorCode::UnterminatedBlockComment => "unterminated-block-comment",
+            ErrorCode::UnknownToken => "unknown-token",
+            ErrorCode::InvalidRawIdentifier => "invalid-raw-identifier",
+            ErrorCode::InvalidEscape => "invalid-escape",
+            ErrorCode::EmptyCharLiteral => "empty-char-literal",
+            ErrorCode::OverlongCharLiteral => "overlong-char-literal",
+            ErrorCode::InvalidSuffix => "invalid-suffix",
+            ErrorCode::InvalidDigit => "invalid-digit",
+            ErrorCode::LiteralOutOfRange => "literal-out-of-range",
+            ErrorCode::VisibilityNotPermitted => "visibility-not-permitted",
+            ErrorCode::InvalidSelfParam => "invalid-self-param",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
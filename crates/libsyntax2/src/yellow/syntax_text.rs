@@ -0,0 +1,159 @@
+use std::fmt;
+
+use {
+    algo::{find_leaf_at_offset, generate},
+    text_utils::{intersect, is_subrange},
+    SyntaxNodeRef, TextRange, TextUnit,
+};
+
+/// Text of a syntax node (or of a part of it).
+///
+/// The text is not stored contiguously: it is a view over the tokens
+/// of the subtree, so creating and slicing `SyntaxText` is cheap.
+/// Use `to_string` (via `Display`) to get an owned copy.
+#[derive(Clone, Copy)]
+pub struct SyntaxText<'a> {
+    node: SyntaxNodeRef<'a>,
+    range: TextRange,
+}
+
+impl<'a> SyntaxText<'a> {
+    pub(crate) fn new(node: SyntaxNodeRef<'a>, range: TextRange) -> SyntaxText<'a> {
+        SyntaxText { node, range }
+    }
+
+    /// Pieces of the text, in order, borrowed from the tokens.
+    pub fn chunks(&self) -> impl Iterator<Item = &'a str> {
+        let range = self.range;
+        let first = if range.is_empty() {
+            None
+        } else {
+            find_leaf_at_offset(self.node, range.start()).right_biased()
+        };
+        generate(first, |token| token.next_token())
+            .take_while(move |token| token.range().start() < range.end())
+            .filter_map(move |token| {
+                let token_range = token.range();
+                let range = intersect(range, token_range)?;
+                if range.is_empty() {
+                    return None;
+                }
+                let range = TextRange::from_to(
+                    range.start() - token_range.start(),
+                    range.end() - token_range.start(),
+                );
+                Some(&token.borrowed_text()[range])
+            })
+    }
+
+    pub fn push_to(&self, buf: &mut String) {
+        self.chunks().for_each(|chunk| buf.push_str(chunk))
+    }
+
+    pub fn len(&self) -> TextUnit {
+        self.range.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        self.chunks().any(|chunk| chunk.contains(c))
+    }
+
+    /// Offset of the first occurrence of `c`, relative to the start of the text.
+    pub fn find(&self, c: char) -> Option<TextUnit> {
+        let mut acc: TextUnit = 0.into();
+        for chunk in self.chunks() {
+            if let Some(pos) = chunk.find(c) {
+                return Some(acc + TextUnit::from(pos as u32));
+            }
+            acc += TextUnit::of_str(chunk);
+        }
+        None
+    }
+
+    /// The character at `offset`, relative to the start of the text.
+    /// `None` if `offset` is not at a char boundary.
+    pub fn char_at(&self, offset: TextUnit) -> Option<char> {
+        if offset >= self.len() {
+            return None;
+        }
+        let offset = self.range.start() + offset;
+        let token = find_leaf_at_offset(self.node, offset).right_biased()?;
+        let text = token.borrowed_text().as_str();
+        let off = u32::from(offset - token.range().start()) as usize;
+        if !text.is_char_boundary(off) {
+            return None;
+        }
+        text[off..].chars().next()
+    }
+
+    /// A subslice of the text, `range` is relative to the start of the text.
+    /// Panics if `range` is out of bounds or splits a character.
+    pub fn slice(&self, range: TextRange) -> SyntaxText<'a> {
+        let range = TextRange::from_to(
+            self.range.start() + range.start(),
+            self.range.start() + range.end(),
+        );
+        assert!(
+            is_subrange(self.range, range),
+            "invalid slice, range: {:?}, slice: {:?}", self.range, range,
+        );
+        assert!(
+            self.is_char_boundary(range.start()) && self.is_char_boundary(range.end()),
+            "slice is not at char boundaries, slice: {:?}", range,
+        );
+        SyntaxText::new(self.node, range)
+    }
+
+    /// `offset` is absolute, unlike in the public methods.
+    fn is_char_boundary(&self, offset: TextUnit) -> bool {
+        let token = match find_leaf_at_offset(self.node, offset).right_biased() {
+            Some(it) => it,
+            None => return true,
+        };
+        let off = u32::from(offset - token.range().start()) as usize;
+        token.borrowed_text().is_char_boundary(off)
+    }
+}
+
+impl<'a> fmt::Debug for SyntaxText<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_string(), f)
+    }
+}
+
+impl<'a> fmt::Display for SyntaxText<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> From<SyntaxText<'a>> for String {
+    fn from(text: SyntaxText<'a>) -> String {
+        text.to_string()
+    }
+}
+
+impl<'a> PartialEq<str> for SyntaxText<'a> {
+    fn eq(&self, mut rhs: &str) -> bool {
+        for chunk in self.chunks() {
+            if !rhs.starts_with(chunk) {
+                return false;
+            }
+            rhs = &rhs[chunk.len()..];
+        }
+        rhs.is_empty()
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for SyntaxText<'a> {
+    fn eq(&self, rhs: &&'b str) -> bool {
+        self == *rhs
+    }
+}
@@ -0,0 +1,2 @@
+r#match r#fn r#foo_bar r#"raw"# r##"raw"## r#
+r #match
@@ -0,0 +1 @@
+привет _x1 x́y ℘ a‎‏bc d e f　g
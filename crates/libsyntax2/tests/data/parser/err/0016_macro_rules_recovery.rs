@@ -0,0 +1,5 @@
+macro_rules! foo {
+    () => {}
+    ($x:expr) => $x;
+    ;
+}
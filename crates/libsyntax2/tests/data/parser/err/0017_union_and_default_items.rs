@@ -0,0 +1,7 @@
+union U(i32);
+union V;
+union W where T: Copy;
+impl T for S {
+    default struct X;
+    default fn foo() {}
+}
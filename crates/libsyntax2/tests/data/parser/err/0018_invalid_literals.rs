@@ -0,0 +1,15 @@
+fn main() {
+    let _ = 'ab';
+    let _ = '';
+    let _ = b'é';
+    let _ = "\q \x80 \u{110000} ok\n";
+    let _ = b"\u{41}";
+    let _ = "foo"suffix;
+    let _ = 256u8;
+    let _ = -128i8;
+    let _ = 128i8;
+    let _ = 1u7;
+    let _ = 0b102;
+    let _ = 1.0i32;
+}
+/* unterminated /* nested */
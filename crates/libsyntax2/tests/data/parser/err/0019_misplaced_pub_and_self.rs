@@ -0,0 +1,10 @@
+trait T {
+    pub fn f(&self);
+}
+impl T for S {
+    pub fn f(&self) {}
+}
+impl S {
+    pub fn g(self) {}
+}
+fn h(self) {}
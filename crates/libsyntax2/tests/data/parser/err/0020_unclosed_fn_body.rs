@@ -0,0 +1,10 @@
+fn foo() {
+    let x = 92;
+    if x > 0 {
+        bar(x);
+
+fn bar(x: i32) {
+    fn inner() {}
+    inner();
+
+struct S;
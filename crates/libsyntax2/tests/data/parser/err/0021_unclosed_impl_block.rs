@@ -0,0 +1,17 @@
+impl Foo {
+    fn new() -> Foo {
+        Foo { x: 1 }
+
+    pub fn get(&self) -> i32 {
+        self.x
+    }
+
+#[derive(Debug)]
+struct Foo {
+    x: i32,
+}
+
+trait Bar {
+    fn bar(&self) {
+
+impl Bar for Foo {}
@@ -0,0 +1,8 @@
+fn main() {
+    match x {
+        1 => {
+            foo();
+        2 => bar(),
+    }
+
+pub fn baz() {}
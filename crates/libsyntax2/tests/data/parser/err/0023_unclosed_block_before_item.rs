@@ -0,0 +1,12 @@
+fn foo() {
+
+fn bar() {
+    let x = 1;
+
+pub fn baz() {}
+
+fn quux() {
+    let y = 2;
+
+#[test]
+fn test() {}
@@ -0,0 +1,6 @@
+fn foo() {
+    'a: loop {};
+    'b: while true {};
+    'c: for x in () {};
+    'd: {};
+}
@@ -0,0 +1,7 @@
+fn foo(){
+    if break {}
+    while break {}
+    for i in break {}
+    match break {}
+    if break x {}
+}
@@ -0,0 +1,7 @@
+macro_rules! foo {}
+macro_rules! bar {
+    () => {};
+    ($x:expr) => { $x };
+    [$($t:tt)*] => ($($t)*)
+}
+macro_rules! baz(() => ());
@@ -0,0 +1,6 @@
+impl T for Foo {
+    default fn foo() {}
+    default unsafe fn bar() {}
+    default type T = Bar;
+    default const C: i32 = 92;
+}
@@ -0,0 +1,5 @@
+union Foo {}
+union Foo {
+    a: i32,
+    b: f32,
+}
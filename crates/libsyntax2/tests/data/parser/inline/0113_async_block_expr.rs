@@ -0,0 +1,4 @@
+fn foo() {
+    async {};
+    async move {};
+}
@@ -0,0 +1,4 @@
+fn foo() {
+    yield;
+    yield 1;
+}
@@ -0,0 +1,2 @@
+async fn foo() {}
+async unsafe fn bar() {}
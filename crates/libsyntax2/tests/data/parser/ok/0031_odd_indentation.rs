@@ -0,0 +1,30 @@
+mod outdented {
+        fn foo() {}
+    fn bar() {}
+  #[derive(Debug)]
+        struct S;
+ pub fn baz() {}
+}
+
+fn not_indented() {
+let x = 92;
+fn inner() {}
+}
+
+impl Foo {
+    fn foo() {
+        let x = 1;
+#[cfg(test)]
+    fn inner() {}
+pub fn inner2() {}
+    }
+}
+
+trait Bar {
+    fn bar();
+  fn baz();
+}
+
+impl Unindented {
+fn foo() {}
+}
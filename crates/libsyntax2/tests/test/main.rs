@@ -2,17 +2,31 @@ extern crate libsyntax2;
 #[macro_use]
 extern crate assert_eq_text;
 extern crate walkdir;
+#[cfg(feature = "serde")]
+extern crate serde_json;
 
 use std::{
+    panic,
+    sync::{Arc, Mutex},
     fs,
     path::{Path, PathBuf},
     fmt::Write,
 };
 
+use libsyntax2::{
+    File, AstNode, AtomEdit, TextRange, TextUnit, SyntaxNodePtr, AstPtr, GreenInterner,
+    GreenNode, ErrorCode, SyntaxNode, SyntaxElement,
+    ast::{self, make, NameOwner},
+    algo::{self, find_leaf_at_offset, walk, DiffEdit, Direction},
+    lexer,
+    SyntaxKind::*,
+    utils::{dump_tree, TreeMemory},
+};
+
 #[test]
 fn lexer_tests() {
     dir_tests(&["lexer"], |text| {
-        let tokens = libsyntax2::tokenize(text);
+        let (tokens, _) = libsyntax2::tokenize(text);
         dump_tokens(&tokens, text)
     })
 }
@@ -21,10 +35,750 @@ fn lexer_tests() {
 fn parser_tests() {
     dir_tests(&["parser/inline", "parser/ok", "parser/err"], |text| {
         let file = libsyntax2::parse(text);
-        libsyntax2::utils::dump_tree(&file)
+        dump_tree(&file)
     })
 }
 
+#[test]
+fn typed_ast_accessors() {
+    let file = File::parse("fn foo(x: i32) { let y = 92; if let Some(z) = y { z } else { 0 } }");
+    let func = file.functions().next().unwrap();
+    assert_eq!(func.param_list().unwrap().params().count(), 1);
+
+    let body = func.body().unwrap();
+    let let_stmt = match body.statements().next().unwrap() {
+        ast::Stmt::LetStmt(it) => it,
+        _ => panic!("expected a let statement"),
+    };
+    assert_eq!(let_stmt.pat().unwrap().syntax().text(), "y");
+    assert_eq!(let_stmt.initializer().unwrap().syntax().text(), "92");
+
+    let if_expr = match body.expr().unwrap() {
+        ast::Expr::IfExpr(it) => it,
+        _ => panic!("expected an if expression"),
+    };
+    let condition = if_expr.condition().unwrap();
+    assert_eq!(condition.pat().unwrap().syntax().text(), "Some(z)");
+    assert_eq!(condition.expr().unwrap().syntax().text(), "y");
+    assert_eq!(if_expr.then_branch().unwrap().syntax().text(), "{ z }");
+    assert_eq!(if_expr.else_branch().unwrap().syntax().text(), "{ 0 }");
+}
+
+#[test]
+fn token_navigation() {
+    let file = File::parse("fn foo() { 92 }");
+    let syntax = file.syntax_ref();
+    assert_eq!(syntax.first_token().unwrap().text(), "fn");
+    assert_eq!(syntax.last_token().unwrap().text(), "}");
+
+    let token = find_leaf_at_offset(syntax, 12.into()).right_biased().unwrap();
+    assert_eq!(token.text(), "92");
+    assert_eq!(token.parent().kind(), LITERAL);
+    let prev = token.prev_token().unwrap();
+    assert_eq!(prev.text(), " ");
+    assert_eq!(prev.prev_token().unwrap().text(), "{");
+    assert_eq!(token.next_token().unwrap().next_token().unwrap().text(), "}");
+    assert!(syntax.last_token().unwrap().next_token().is_none());
+}
+
+#[test]
+fn traversal() {
+    let file = File::parse("fn foo() { 1 + 2; }\nstruct S { f: u32 }");
+    // Owned nodes, which outlive the `File`.
+    let fn_def = file.functions().next().unwrap().syntax().clone();
+    let kinds = |nodes: &mut Iterator<Item = SyntaxNode>| {
+        nodes.map(|it| format!("{:?}", it.kind())).collect::<Vec<_>>().join(" ")
+    };
+    assert_eq!(
+        kinds(&mut fn_def.descendants()),
+        "FN_DEF NAME PARAM_LIST BLOCK_EXPR EXPR_STMT BIN_EXPR LITERAL LITERAL",
+    );
+    assert_eq!(
+        kinds(&mut fn_def.postorder()),
+        "NAME PARAM_LIST LITERAL LITERAL BIN_EXPR EXPR_STMT BLOCK_EXPR FN_DEF",
+    );
+
+    let mut preorder = walk::preorder(file.syntax_ref());
+    let mut visited = Vec::new();
+    while let Some(node) = preorder.next() {
+        if node.kind() == FN_DEF || node.kind() == NAMED_FIELD {
+            preorder.skip_subtree();
+        }
+        visited.push(format!("{:?}", node.kind()));
+    }
+    assert_eq!(visited.join(" "), "FILE FN_DEF STRUCT_DEF NAME NAMED_FIELD");
+
+    let plus = file.syntax().leaf_at_offset(13.into()).right_biased().unwrap();
+    assert_eq!(plus.kind(), PLUS);
+    let covering = file.syntax().covering_element(TextRange::from_to(11.into(), 16.into()));
+    assert_eq!(covering.kind(), BIN_EXPR);
+    let prev = SyntaxElement::Token(plus).non_trivia_sibling(Direction::Backward).unwrap();
+    assert_eq!(prev.text().to_string(), "1");
+    let field = algo::find_node_at_range::<_, ast::NamedField<_>>(
+        file.syntax().clone(),
+        TextRange::from_to(32.into(), 33.into()),
+    );
+    assert_eq!(field.unwrap().name().unwrap().text(), "f");
+
+    let literals: Vec<ast::Literal<_>> = file.descendants().collect();
+    assert_eq!(literals.len(), 2);
+}
+
+#[test]
+fn syntax_text() {
+    let file = File::parse("fn foo() { bar(92, \"baz\") }");
+    let func = file.functions().next().unwrap();
+    let body = func.body().unwrap();
+    let text = body.syntax().text();
+    assert_eq!(text, "{ bar(92, \"baz\") }");
+    assert_eq!(text.len(), TextUnit::from(18));
+    assert!(text.contains('"'));
+    assert!(!text.contains('x'));
+    assert_eq!(text.find('('), Some(TextUnit::from(5)));
+    assert_eq!(text.char_at(6.into()), Some('9'));
+    assert_eq!(text.char_at(18.into()), None);
+
+    let slice = text.slice(TextRange::from_to(3.into(), 8.into()));
+    assert_eq!(slice, "ar(92");
+    assert_eq!(slice.chunks().collect::<Vec<_>>(), vec!["ar", "(", "92"]);
+    assert_eq!(slice.to_string(), "ar(92");
+
+    let file = File::parse("fn foo() { \"Ферзь\" }");
+    let text = file.syntax().text();
+    assert_eq!(text.char_at(12.into()), Some('Ф'));
+    assert_eq!(text.char_at(13.into()), None);
+    let slice = text.slice(TextRange::from_to(11.into(), 14.into()));
+    assert_eq!(slice.chunks().collect::<Vec<_>>(), vec!["\"Ф"]);
+    let split_char = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        text.slice(TextRange::from_to(11.into(), 13.into()))
+    }));
+    assert!(split_char.is_err());
+}
+
+#[test]
+fn syntax_ptrs_survive_reparse() {
+    let text = "fn foo() {}\nfn bar() { baz }";
+    let file = File::parse(text);
+    let bar = file.functions().nth(1).unwrap();
+    let bar_ptr: AstPtr<ast::FnDef> = AstPtr::new(&bar);
+    let name_ref = walk::preorder(bar.syntax_ref())
+        .find(|it| it.kind() == NAME_REF)
+        .unwrap();
+    let name_ref_ptr = SyntaxNodePtr::new(name_ref);
+
+    let file = File::parse(text);
+    assert_eq!(bar_ptr.resolve(&file).unwrap().name().unwrap().text(), "bar");
+    let resolved = name_ref_ptr.resolve(&file).unwrap();
+    assert_eq!(resolved.kind(), NAME_REF);
+    assert_eq!(resolved.text(), "baz");
+
+    let edit = AtomEdit::insert(2.into(), "o".to_string());
+    let file = file.reparse(&edit);
+    assert!(bar_ptr.resolve(&file).is_none());
+}
+
+#[test]
+fn syntax_ptrs_resolve_every_node() {
+    let mut texts = collect_tests(&["parser/inline", "parser/ok", "parser/err"])
+        .iter()
+        .map(|path| read_text(path))
+        .collect::<Vec<_>>();
+    // An empty `ERROR` node right after `&`.
+    texts.push("fn f(&) {}".to_string());
+    for text in texts {
+        let file = File::parse(&text);
+        for node in walk::preorder(file.syntax_ref()) {
+            let resolved = SyntaxNodePtr::new(node).resolve(&file);
+            assert_eq!(
+                resolved.map(|it| (it.kind(), it.range())),
+                Some((node.kind(), node.range())),
+                "text: {}", text,
+            );
+        }
+    }
+}
+
+#[test]
+fn interned_trees_are_smaller() {
+    let texts = [
+        "fn foo(x: i32) -> i32 { x + 1 }",
+        "fn bar(y: i32) -> i32 { foo(y) + 1 }",
+    ];
+    let mut interner = GreenInterner::new();
+    let mut plain = TreeMemory::new();
+    let mut interned = TreeMemory::new();
+    for text in texts.iter() {
+        let file = File::parse(text);
+        let interned_file = File::parse_with_interner(text, &mut interner);
+        assert_eq_text!(&dump_tree(file.syntax()), &dump_tree(interned_file.syntax()));
+        plain.add(file.syntax());
+        interned.add(interned_file.syntax());
+    }
+    assert!(!interner.is_empty());
+    assert!(
+        interned.total() < plain.total(),
+        "interned: {}, plain: {}", interned.total(), plain.total(),
+    );
+
+    // Parsing with a locked interner shares the nodes the same way.
+    let shared = Mutex::new(interner);
+    let file = File::parse_with_shared_interner(texts[0], || shared.lock().unwrap());
+    let plain_bytes = TreeMemory::new().add(File::parse(texts[0]).syntax());
+    assert!(interned.add(file.syntax()) < plain_bytes);
+}
+
+#[test]
+fn interner_gc_bounds_memory() {
+    fn version(i: usize) -> String {
+        format!("fn foo() {{ bar({}, \"a string which is too long to be inlined\") }}", i)
+    }
+
+    let mut interner = GreenInterner::new();
+    let mut file = File::parse_with_interner(&version(0), &mut interner);
+    interner.gc();
+    let live_len = interner.len();
+    for i in 1..100 {
+        file = File::parse_with_interner(&version(i), &mut interner);
+        interner.gc();
+        assert_eq!(interner.len(), live_len);
+    }
+    // Nodes of the live tree are still shared.
+    let other = File::parse_with_interner(&version(99), &mut interner);
+    let mut memory = TreeMemory::new();
+    let file_bytes = memory.add(file.syntax());
+    assert!(memory.add(other.syntax()) < file_bytes);
+
+    drop((file, other, memory));
+    interner.gc();
+    assert!(interner.is_empty());
+}
+
+#[test]
+fn tree_mutation() {
+    let file = File::parse("fn foo(x: i32, y: i32) {}\nfn bar() {}");
+    let foo = file.functions().next().unwrap();
+    let params = foo.param_list().unwrap();
+
+    let name = foo.name().unwrap().syntax().first_token().unwrap();
+    let new_name = GreenNode::new_leaf(IDENT, "baz");
+    let new_root = name.replace_with(new_name);
+    assert_eq!(new_root.text(), "fn baz(x: i32, y: i32) {}\nfn bar() {}");
+    let old_bar = file.syntax().last_child().unwrap();
+    let new_bar = new_root.last_child().unwrap();
+    match (old_bar.green(), new_bar.green()) {
+        (GreenNode::Branch(old), GreenNode::Branch(new)) => assert!(Arc::ptr_eq(old, new)),
+        _ => panic!("expected branches"),
+    }
+
+    // `(`, `x: i32`, `,`, ` `, `y: i32`, `)`
+    let children: Vec<_> = params.syntax().children_with_tokens().collect();
+    let new_root = params.syntax().replace_children(2..5, None);
+    assert_eq!(new_root.text(), "fn foo(x: i32) {}\nfn bar() {}");
+
+    let new_root = params.syntax().remove_child(children[2].clone());
+    assert_eq!(new_root.text(), "fn foo(x: i32 y: i32) {}\nfn bar() {}");
+
+    let new_root = params.syntax().insert_children(1, vec![
+        children[4].green().clone(),
+        GreenNode::new_leaf(COMMA, ","),
+        GreenNode::new_leaf(WHITESPACE, " "),
+    ]);
+    assert_eq!(new_root.text(), "fn foo(y: i32, x: i32, y: i32) {}\nfn bar() {}");
+}
+
+#[test]
+fn ast_make() {
+    let path = make::path_qualified(make::path("foo"), make::path_segment(make::name_ref("bar")));
+    assert_eq!(path.syntax().text(), "foo::bar");
+    assert_eq!(path.syntax().range(), TextRange::from_to(0.into(), 8.into()));
+
+    let call = make::expr_call(
+        make::expr_path(path),
+        make::arg_list(vec![make::expr_path(make::path("x")), make::expr_path(make::path("y"))]),
+    );
+    assert_eq!(call.syntax().text(), "foo::bar(x, y)");
+
+    let arm = make::match_arm(
+        vec![ast::Pat::PlaceholderPat(make::placeholder_pat())],
+        make::expr_path(make::path("None")),
+    );
+    assert_eq!(arm.syntax().text(), "_ => None");
+
+    let param = make::param(ast::Pat::BindPat(make::bind_pat(make::name("x"))), "i32");
+    let body = make::block_expr(vec![make::expr_stmt(call)], None);
+    let func = make::fn_def(make::name("f"), make::param_list(vec![param]), body);
+    assert_eq!(func.syntax().text(), "fn f(x: i32) {\n    foo::bar(x, y);\n}");
+    assert_eq!(func.name().unwrap().text(), "f");
+
+    let attr = make::attr("derive(Clone)");
+    assert_eq!(attr.syntax().text(), "#[derive(Clone)]");
+    assert_eq!(attr.as_call().unwrap().0, "derive");
+
+    let tree = make::use_tree(make::use_path("std::sync::Arc"), Some(make::name("A")));
+    assert_eq!(tree.syntax().text(), "std::sync::Arc as A");
+    let item = make::use_item(tree);
+    assert_eq!(item.syntax().text(), "use std::sync::Arc as A;");
+    let item = make::use_item(make::use_tree(make::use_path("self::foo"), None));
+    assert_eq!(item.syntax().text(), "use self::foo;");
+    assert!(panic::catch_unwind(|| make::use_path("Vec::<u8>")).is_err());
+    assert!(panic::catch_unwind(|| make::use_tree(make::path("Vec::<u8>::new"), None)).is_err());
+
+    let path = make::path("Vec::<u8>::new");
+    assert_eq!(path.syntax().text(), "Vec::<u8>::new");
+    assert_eq!(path.segment().unwrap().syntax().text(), "new");
+    let path = make::path_qualified(make::path("<T as Tr>::f"), make::path_segment(make::name_ref("g")));
+    assert_eq!(make::expr_path(path).syntax().text(), "<T as Tr>::f::g");
+}
+
+#[test]
+fn literal_values() {
+    let file = File::parse(r##"
+fn f() {
+    "a\tb\u{1F600}"; r#"raw\n"#x; 'z'; b'\xFF'; b"by\x41"; br"raw";
+    0xFF_u8; 1_000; 1.5e3f32; "\q\n\x"; '';
+}
+"##);
+    let literals: Vec<_> = walk::preorder(file.syntax_ref())
+        .filter_map(ast::Literal::cast)
+        .map(|it| it.kind())
+        .collect();
+    let lit = |idx: usize| literals[idx];
+    match (lit(0), lit(1), lit(2), lit(3), lit(4), lit(5)) {
+        (
+            ast::LiteralKind::String(s),
+            ast::LiteralKind::RawString(raw),
+            ast::LiteralKind::Char(c),
+            ast::LiteralKind::Byte(b),
+            ast::LiteralKind::ByteString(bs),
+            ast::LiteralKind::RawByteString(raw_bs),
+        ) => {
+            assert_eq!(s.value().unwrap(), "a\tb\u{1F600}");
+            assert_eq!(s.suffix(), None);
+            assert_eq!(raw.value(), Some("raw\\n"));
+            assert_eq!(raw.suffix(), Some("x"));
+            assert_eq!(c.value(), Ok('z'));
+            assert_eq!(b.value(), Ok(0xFF));
+            assert_eq!(bs.value().unwrap(), b"byA".to_vec());
+            assert_eq!(raw_bs.value(), Some(&b"raw"[..]));
+        }
+        _ => panic!("unexpected literal kinds"),
+    }
+    match (lit(6), lit(7), lit(8)) {
+        (
+            ast::LiteralKind::IntNumber(hex),
+            ast::LiteralKind::IntNumber(dec),
+            ast::LiteralKind::FloatNumber(float),
+        ) => {
+            assert_eq!((hex.radix(), hex.value(), hex.suffix()), (16, Some(255), Some("u8")));
+            assert_eq!((dec.radix(), dec.value(), dec.suffix()), (10, Some(1000), None));
+            assert_eq!((float.value(), float.suffix()), (Some(1500.0), Some("f32")));
+        }
+        _ => panic!("unexpected literal kinds"),
+    }
+    match (lit(9), lit(10)) {
+        (ast::LiteralKind::String(s), ast::LiteralKind::Char(c)) => {
+            let errors: Vec<_> = s.value().unwrap_err().into_iter()
+                .map(|e| (e.range, e.msg))
+                .collect();
+            assert_eq!(errors, vec![
+                (TextRange::from_to(109.into(), 111.into()), "unknown character escape"),
+                (TextRange::from_to(113.into(), 115.into()), "invalid `\\x` escape, expected two hex digits"),
+            ]);
+            assert_eq!(c.value().unwrap_err()[0].msg, "empty character literal");
+        }
+        _ => panic!("unexpected literal kinds"),
+    }
+}
+
+#[test]
+fn raw_identifiers() {
+    let file = File::parse("fn r#match() { r#match(); }");
+    assert!(file.errors().is_empty());
+    let func = file.functions().next().unwrap();
+    let name = func.name().unwrap();
+    assert_eq!(name.text(), "match");
+    assert!(name.is_raw());
+    let name_ref = walk::preorder(file.syntax_ref())
+        .filter_map(ast::NameRef::cast)
+        .next()
+        .unwrap();
+    assert_eq!(name_ref.text(), "match");
+    assert!(name_ref.is_raw());
+
+    for name in ["_", "self", "super", "crate", "Self"].iter() {
+        let (_, errors) = lexer::tokenize(&format!("r#{}", name));
+        assert_eq!(errors.len(), 1, "r#{}", name);
+        assert_eq!(errors[0].code, ErrorCode::InvalidRawIdentifier);
+    }
+}
+
+#[test]
+fn expected_tokens() {
+    let file = File::parse("struct S { a: u32 b: u32 }");
+    let errors = file.errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].msg, "expected one of `+` or `,`");
+    assert_eq!(errors[0].expected, vec![PLUS, COMMA]);
+
+    let file = File::parse("fn foo() { let x = 92 }\nfn");
+    let errors = file.errors();
+    assert_eq!(errors[0].msg, "expected `;`");
+    assert_eq!(errors[0].expected, vec![SEMI]);
+    assert_eq!(errors[1].msg, "expected a name");
+    assert!(errors[1].expected.is_empty());
+}
+
+#[test]
+fn lexer_errors() {
+    fn first_error(text: &str) -> (ErrorCode, TextRange) {
+        let file = File::parse(text);
+        let error = &file.errors()[0];
+        (error.code, error.range)
+    }
+
+    assert_eq!(
+        first_error("fn f() { let s = \"abc; }"),
+        (ErrorCode::UnterminatedString, TextRange::from_to(17.into(), 18.into())),
+    );
+    assert_eq!(
+        first_error("fn f() { let s = br##\"abc\"#; }"),
+        (ErrorCode::UnterminatedString, TextRange::from_to(17.into(), 22.into())),
+    );
+    assert_eq!(
+        first_error("fn f() { let c = '\\n; }"),
+        (ErrorCode::UnterminatedChar, TextRange::from_to(17.into(), 18.into())),
+    );
+    assert_eq!(
+        first_error("fn f() {} /* comment"),
+        (ErrorCode::UnterminatedBlockComment, TextRange::from_to(10.into(), 12.into())),
+    );
+    assert_eq!(
+        first_error("fn f() { 1 \u{20AC} 2 }"),
+        (ErrorCode::UnknownToken, TextRange::from_to(11.into(), 14.into())),
+    );
+    assert_eq!(
+        first_error("fn f( {}\nfn g() { let s = \"abc; }"),
+        (ErrorCode::ExpectedPattern, TextRange::from_to(6.into(), 6.into())),
+    );
+}
+
+#[test]
+fn relex_matches_full_tokenize() {
+    fn do_check(before: &str, replace_with: &str) -> lexer::TokenDiff {
+        let (range, before) = extract_range(before);
+        let edit = AtomEdit::replace(range, replace_with.to_string());
+        let (mut tokens, _) = lexer::tokenize(&before);
+        let diff = lexer::relex(&tokens, &before, &edit);
+        diff.apply(&mut tokens);
+
+        let mut after = before.clone();
+        edit_text(&mut after, &edit);
+        let (expected_tokens, expected_errors) = lexer::tokenize(&after);
+        assert_eq!(tokens, expected_tokens, "{:?} -> {:?}", before, after);
+        let insert_len: TextUnit = diff.insert.iter().map(|it| it.len).sum();
+        let insert_range = TextRange::offset_len(diff.offset, insert_len);
+        let expected_errors: Vec<_> = expected_errors.into_iter()
+            .filter(|e| e.range.start() >= insert_range.start() && e.range.start() < insert_range.end())
+            .collect();
+        assert_eq!(diff.errors, expected_errors);
+        diff
+    }
+
+    let diff = do_check("fn foo() { let <|>x<|> = 92; }", "xyz");
+    assert_eq!(diff.delete, 10..11);
+    assert_eq!(diff.insert.len(), 1);
+    let diff = do_check("fn foo() { \"hello<|><|>\" }", ", world");
+    assert_eq!(diff.delete, 8..9);
+    assert_eq!(diff.insert.len(), 1);
+
+    do_check("fn foo() { let x = 1<|><|>; }", ".5");
+    do_check("fn foo() { let x = 1<|><|>; }", ".");
+    do_check("fn foo() { let x = r<|><|>; }", "\"");
+    do_check("fn foo() { let x = r<|><|>; }", "#match");
+    do_check("fn foo() { let x = <|>/*<|> 1; }", "");
+    do_check("fn foo() { <|><|> }\nfn bar() {}", "/*");
+    do_check("fn foo() { <|><|> }", "\"");
+    do_check("fn foo() { let x = 1; }<|><|>", "\n// comment");
+    do_check("<|>fn<|> foo() {}", "");
+    do_check("<|><|>", "fn main() {}");
+    do_check("fn foo() { 'a<|><|> }", "'");
+    do_check("fn foo() { a <|><|>= b }", "=");
+    do_check("fn foo() { \u{20AC}<|><|> }", "x");
+
+    let long = "fn bar() { \"Ферзь\" + 1 }\n".repeat(100);
+    do_check(&format!("fn foo() {{ <|><|> }}\n{}", long), "\"");
+    do_check(&format!("fn foo() {{ <|><|> }}\n{}", long), "/*");
+    do_check(&format!("{}fn foo() {{ <|>x<|> }}\n{}", long, long), "xyz");
+    do_check(&format!("{}<|><|>", long), "/* comment */");
+}
+
+#[test]
+fn tree_diff() {
+    fn do_check(before: &str, after: &str, expected: &str) {
+        let old = File::parse(before);
+        let new = File::parse(after);
+        let diff = algo::diff(old.syntax_ref(), new.syntax_ref());
+        let actual = diff.edits.iter()
+            .map(|edit| match *edit {
+                DiffEdit::Insert(new) => format!("insert {:?}", new),
+                DiffEdit::Delete(old) => format!("delete {:?}", old),
+                DiffEdit::Replace { old, new } => format!("replace {:?} with {:?}", old, new),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq_text!(expected, &actual);
+    }
+
+    do_check("fn foo() {}", "fn foo() {}", "");
+    do_check(
+        "fn foo() {}\nfn bar() { 1 }\nfn baz() {}",
+        "fn foo() {}\nfn bar() { 92 }\nfn baz() {}",
+        r#"replace INT_NUMBER@[23; 24) "1" with INT_NUMBER@[23; 25) "92""#,
+    );
+    do_check(
+        "fn foo() {}\nfn baz() {}",
+        "fn foo() {}\nfn bar() {}\nfn baz() {}",
+        "insert FN_DEF@[12; 23)\ninsert WHITESPACE@[23; 24)",
+    );
+    do_check(
+        "fn foo() {}\nstruct S;\nfn baz() {}",
+        "fn foo() {}\nfn baz() {}",
+        "delete STRUCT_DEF@[12; 21)\ndelete WHITESPACE@[21; 22)",
+    );
+    do_check(
+        "fn foo() { 1 }",
+        "fn foo() { (1) }",
+        "replace LITERAL@[11; 12) with PAREN_EXPR@[11; 14)",
+    );
+
+    // Subtrees shared after an incremental reparse are not compared.
+    let old = File::parse("fn foo() { 1 + 1 }\nfn bar() {}");
+    let edit = AtomEdit::replace(TextRange::from_to(15.into(), 16.into()), "2".to_string());
+    let new = old.incremental_reparse(&edit).unwrap();
+    let diff = algo::diff(old.syntax_ref(), new.syntax_ref());
+    assert_eq!(diff.changed_ranges(), vec![TextRange::from_to(15.into(), 16.into())]);
+}
+
+#[test]
+fn reparse_test() {
+    fn do_check(before: &str, replace_with: &str) {
+        let (range, before) = extract_range(before);
+        let edit = AtomEdit::replace(range, replace_with.to_string());
+        let after = {
+            let mut after = before.clone();
+            edit_text(&mut after, &edit);
+            after
+        };
+
+        let fully_reparsed = File::parse(&after);
+        let incrementally_reparsed = File::parse(&before)
+            .incremental_reparse(&edit)
+            .expect("cannot incrementally reparse");
+        assert_eq_text!(
+            &dump_tree(fully_reparsed.syntax()),
+            &dump_tree(incrementally_reparsed.syntax())
+        )
+    }
+
+    do_check(r"
+fn foo() {
+    let x = foo + <|>bar<|>
+}
+", "baz");
+    do_check(r"
+fn foo() {
+    let x = foo<|> + bar<|>
+}
+", "baz");
+    do_check(r"
+fn foo {
+    let;
+    1 + 1;
+    <|>92<|>;
+}
+", "62");
+    do_check(r"
+trait Foo {
+    type <|>Foo<|>;
+}
+", "Output");
+    do_check(r"
+impl IntoIterator<Item=i32> for Foo {
+    f<|><|>
+}
+", "n next() {}");
+    do_check(r"
+foo!{a, b<|><|> d}
+", ", c[3]");
+    do_check(r"
+fn foo() {
+    macro_rule<|><|>! bar {}
+}
+", "s");
+    do_check(r"
+fn foo() {
+    vec![<|><|>]
+}
+", "123");
+    do_check(r"
+fn foo<|><|>() {}
+", "o");
+    do_check(r#"
+fn foo() {
+    let x = "hello<|><|>";
+}
+"#, " world");
+    do_check(r"
+fn foo() {
+    // comment<|><|>
+    foo();
+}
+", " more");
+    do_check(r"
+fn foo() {<|><|>
+    foo();
+}
+", "   ");
+    do_check(r"
+        fn foo() {
+  foo();
+    <|><|>
+}
+", "fn bar() {}");
+    do_check(r#"
+fn foo() {
+    let x = "hello<|><|>";
+}
+"#, r"\q");
+    do_check(r"
+fn foo() {
+    let x = 1<|>u8<|>;
+}
+", "u9");
+    do_check(r"
+impl Foo for Bar {
+    <|>pub <|>fn foo(&self) {}
+}
+", "");
+}
+
+#[test]
+fn reparse_is_incremental() {
+    fn do_check(before: &str, replace_with: &str, kind_of_reparsed: &str) {
+        let (range, before) = extract_range(before);
+        let edit = AtomEdit::replace(range, replace_with.to_string());
+        let file = File::parse(&before);
+        let reparsed = file.incremental_reparse(&edit)
+            .expect("cannot incrementally reparse");
+        let old_tree = dump_tree(file.syntax());
+        let new_tree = dump_tree(reparsed.syntax());
+        assert_ne!(old_tree, new_tree);
+        assert!(
+            new_tree.contains(kind_of_reparsed),
+            "{} is not in\n{}", kind_of_reparsed, new_tree,
+        );
+    }
+
+    do_check("fn foo() { 1 + <|>2<|> }", "92", "BLOCK_EXPR");
+    do_check("fn foo() {}\nfn <|>bar<|>() {}", "baz", "IDENT@[15; 18) \"baz\"");
+    do_check("impl Foo {<|><|>}", "fn bar() {}", "ITEM_LIST");
+}
+
+#[test]
+fn reparse_falls_back_to_full_reparse() {
+    fn do_check(before: &str, replace_with: &str) {
+        let (range, before) = extract_range(before);
+        let edit = AtomEdit::replace(range, replace_with.to_string());
+        let file = File::parse(&before);
+        assert!(file.incremental_reparse(&edit).is_none());
+        let mut after = before.clone();
+        edit_text(&mut after, &edit);
+        assert_eq_text!(
+            &dump_tree(File::parse(&after).syntax()),
+            &dump_tree(file.reparse(&edit).syntax())
+        );
+    }
+
+    do_check("fn foo() { <|><|> }", "}");
+    do_check("fn foo() { let x = \"<|><|>\"; }", "\"");
+    do_check("fn <|>foo<|>() {}", "union");
+    do_check("fn foo() {}<|><|>", " fn bar() {}");
+    do_check("struct Foo {\n    f: foo<|><|>\n}", ",\n    g: (),");
+    do_check("mod foo {\n    fn <|><|>\n}", "bar");
+    do_check("use a::b::{foo,<|>,bar<|>};", "baz");
+    do_check("pub enum A {\n    Foo<|><|>\n}", "\nBar;\n");
+    do_check("extern {\n    fn<|>;<|>\n}", " exit(code: c_int)");
+    do_check("fn foo() {\n    let x = 1 + 2<|><|>;\n}", " }\nfn bar() {");
+    do_check("fn foo() {\n    foo();\n<|><|>\n}", "fn bar() {}");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let text = "\
+#[derive(Debug)]
+struct S<'a> { x: &'a str }
+
+// comment
+fn foo(s: S) -> bool {
+    s.x.len() >>= 1u8 + 'c' as usize
+}
+";
+    let file = File::parse(text);
+    assert!(file.errors().is_empty());
+    let json = serde_json::to_string(file.syntax()).unwrap();
+    let green: GreenNode = serde_json::from_str(&json).unwrap();
+    let node = SyntaxNode::new(green, Vec::new());
+    assert_eq_text!(text, &node.text().to_string());
+    assert_eq_text!(&dump_tree(file.syntax()), &dump_tree(&node));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_json_format() {
+    let file = File::parse("struct S;");
+    let json = serde_json::to_string(file.syntax()).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"kind":"FILE","range":[0,9],"children":["#,
+            r#"{"kind":"STRUCT_DEF","range":[0,9],"children":["#,
+            r#"{"kind":"STRUCT_KW","range":[0,6],"text":"struct"},"#,
+            r#"{"kind":"WHITESPACE","range":[6,7],"text":" "},"#,
+            r#"{"kind":"NAME","range":[7,8],"children":["#,
+            r#"{"kind":"IDENT","range":[7,8],"text":"S"}"#,
+            r#"]},"#,
+            r#"{"kind":"SEMI","range":[8,9],"text":";"}"#,
+            r#"]}]}"#,
+        ),
+    );
+
+    // Ranges are optional when deserializing.
+    let green: GreenNode = serde_json::from_str(
+        r#"{"kind":"NAME","children":[{"kind":"IDENT","text":"S"}]}"#,
+    ).unwrap();
+    assert_eq!(dump_tree(&SyntaxNode::new(green, Vec::new())), "NAME@[0; 1)\n  IDENT@[0; 1) \"S\"\n");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_errors() {
+    fn check(json: &str, message: &str) {
+        let err = serde_json::from_str::<GreenNode>(json).unwrap_err().to_string();
+        assert!(err.contains(message), "expected `{}`, got `{}`", message, err);
+    }
+
+    check(r#"{"kind":"FOO","text":"x"}"#, "unknown syntax kind `FOO`");
+    check(r#"{"text":"x"}"#, "missing field `kind`");
+    check(r#"{"kind":"IDENT"}"#, "expected either `text` or `children`");
+    check(r#"{"kind":"IDENT","text":"x","children":[]}"#, "a token can't have `children`");
+    check(r#"{"kind":"FN_DEF","text":"fn"}"#, "`FN_DEF` is a node kind");
+    check(r#"{"kind":"IDENT","children":[]}"#, "`IDENT` is a token kind");
+    check(r#"{"kind":"FN_KW","text":"foo"}"#, "the text of `FN_KW` should be `fn`");
+    check(r#"{"kind":"NAME","children":[{"kind":"NAME"}]}"#, "expected either `text` or `children`");
+    assert!(serde_json::from_str::<GreenNode>(r#"{"kind":"ERROR","children":[]}"#).is_ok());
+}
+
 
 /// Read file and normalize newlines.
 ///
@@ -134,3 +888,22 @@ fn dump_tokens(tokens: &[libsyntax2::Token], text: &str) -> String {
     }
     acc
 }
+
+fn extract_range(text: &str) -> (TextRange, String) {
+    let marker = "<|>";
+    let start = text.find(marker).expect("text should contain range markers");
+    let mut text = format!("{}{}", &text[..start], &text[start + marker.len()..]);
+    let end = text.find(marker).expect("text should contain range markers");
+    text = format!("{}{}", &text[..end], &text[end + marker.len()..]);
+    let range = TextRange::from_to(
+        TextUnit::from(start as u32),
+        TextUnit::from(end as u32),
+    );
+    (range, text)
+}
+
+fn edit_text(text: &mut String, edit: &AtomEdit) {
+    let start = u32::from(edit.delete.start()) as usize;
+    let end = u32::from(edit.delete.end()) as usize;
+    text.replace_range(start..end, &edit.insert);
+}
@@ -35,7 +35,7 @@ pub fn server_capabilities() -> ServerCapabilities {
         rename_provider: None,
         color_provider: None,
         execute_command_provider: Some(ExecuteCommandOptions {
-            commands: vec!["apply_code_action".to_string()],
+            commands: vec!["apply_code_action".to_string(), "ssr".to_string()],
         }),
     }
 }
@@ -32,6 +32,7 @@ impl Conv for SyntaxKind {
         match self {
             SyntaxKind::FN_DEF => SymbolKind::Function,
             SyntaxKind::STRUCT_DEF => SymbolKind::Struct,
+            SyntaxKind::UNION_DEF => SymbolKind::Struct,
             SyntaxKind::ENUM_DEF => SymbolKind::Enum,
             SyntaxKind::TRAIT_DEF => SymbolKind::Interface,
             SyntaxKind::MODULE => SymbolKind::Module,
@@ -39,6 +40,7 @@ impl Conv for SyntaxKind {
             SyntaxKind::STATIC_DEF => SymbolKind::Constant,
             SyntaxKind::CONST_DEF => SymbolKind::Constant,
             SyntaxKind::IMPL_ITEM => SymbolKind::Object,
+            SyntaxKind::MACRO_RULES => SymbolKind::Function,
             _ => SymbolKind::Variable,
         }
     }
@@ -3,10 +3,10 @@ use std::collections::HashMap;
 use languageserver_types::{
     Diagnostic, DiagnosticSeverity, Url, DocumentSymbol,
     Command, TextDocumentIdentifier, WorkspaceEdit,
-    SymbolInformation, Position,
+    SymbolInformation, Position, NumberOrString,
 };
 use libanalysis::{World, Query};
-use libeditor::{self, CursorPosition};
+use libeditor::{self, CursorPosition, SsrRule};
 use libsyntax2::TextUnit;
 use serde_json::{to_value, from_value};
 
@@ -196,13 +196,18 @@ pub fn handle_execute_command(
     path_map: PathMap,
     mut params: req::ExecuteCommandParams,
 ) -> Result<(req::ApplyWorkspaceEditParams, Option<Position>)> {
-    if params.command.as_str() != "apply_code_action" {
-        bail!("unknown cmd: {:?}", params.command);
+    match params.command.as_str() {
+        "apply_code_action" | "ssr" => (),
+        _ => bail!("unknown cmd: {:?}", params.command),
     }
     if params.arguments.len() != 1 {
         bail!("expected single arg, got {}", params.arguments.len());
     }
     let arg = params.arguments.pop().unwrap();
+    if params.command.as_str() == "ssr" {
+        let rule: String = from_value(arg)?;
+        return Ok((structural_replace(&world, &path_map, &rule)?, None));
+    }
     let arg: ActionRequest = from_value(arg)?;
     let file_id = arg.text_document.try_conv_with(&path_map)?;
     let file = world.file_syntax(file_id)?;
@@ -229,6 +234,27 @@ pub fn handle_execute_command(
     Ok((edit, cursor_pos))
 }
 
+fn structural_replace(
+    world: &World,
+    path_map: &PathMap,
+    rule: &str,
+) -> Result<req::ApplyWorkspaceEditParams> {
+    let rule = match SsrRule::parse(rule) {
+        Ok(rule) => rule,
+        Err(e) => bail!("invalid ssr rule: {}", e),
+    };
+    let mut changes = HashMap::new();
+    for (file_id, edit) in world.structural_replace(&rule) {
+        let line_index = world.file_line_index(file_id)?;
+        changes.insert(file_id.try_conv_with(path_map)?, edit.conv_with(&line_index));
+    }
+    let edit = WorkspaceEdit {
+        changes: Some(changes),
+        document_changes: None,
+    };
+    Ok(req::ApplyWorkspaceEditParams { edit })
+}
+
 #[derive(Serialize, Deserialize)]
 struct ActionRequest {
     id: ActionId,
@@ -277,7 +303,7 @@ pub fn publish_diagnostics(
         .map(|d| Diagnostic {
             range: d.range.conv_with(&line_index),
             severity: Some(DiagnosticSeverity::Error),
-            code: None,
+            code: d.code.map(|code| NumberOrString::String(code.as_str().to_string())),
             source: Some("libsyntax2".to_string()),
             message: d.msg,
             related_information: None,
@@ -173,7 +173,17 @@ fn on_request(
         pool.execute(move || {
             let (edit, cursor) = match handle_execute_command(world, path_map, params) {
                 Ok(res) => res,
-                Err(e) => return sender.send(Task::Die(e)),
+                // The command has already been acknowledged, so errors,
+                // like an invalid search and replace rule, are shown to
+                // the user instead of stopping the server.
+                Err(e) => {
+                    let params = req::ShowMessageParams {
+                        typ: req::MessageType::Error,
+                        message: e.to_string(),
+                    };
+                    let not = dispatch::send_notification::<req::ShowMessage>(params);
+                    return sender.send(Task::Notify(not));
+                }
             };
             match to_value(edit) {
                 Err(e) => return sender.send(Task::Die(e.into())),
@@ -10,6 +10,7 @@ pub use languageserver_types::{
     ExecuteCommandParams,
     WorkspaceSymbolParams,
     TextDocumentPositionParams,
+    ShowMessageParams, MessageType,
 };
 
 
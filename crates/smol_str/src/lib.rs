@@ -1,4 +1,4 @@
-use std::{sync::Arc, ops::Deref, fmt};
+use std::{sync::Arc, ops::Deref, fmt, hash, borrow::Borrow};
 
 #[derive(Clone)]
 pub struct SmolStr(Repr);
@@ -15,6 +15,23 @@ impl SmolStr {
     pub fn to_string(&self) -> String {
         self.as_str().to_string()
     }
+
+    /// Short strings are stored inline, only long ones
+    /// allocate (and are shared between clones).
+    pub fn is_heap_allocated(&self) -> bool {
+        match self.0 {
+            Repr::Heap(..) => true,
+            Repr::Inline { .. } => false,
+        }
+    }
+
+    /// Is this the only clone of a heap allocated string?
+    pub fn is_unique(&self) -> bool {
+        match &self.0 {
+            Repr::Heap(data) => Arc::strong_count(data) == 1,
+            Repr::Inline { .. } => false,
+        }
+    }
 }
 
 impl Deref for SmolStr {
@@ -25,6 +42,26 @@ impl Deref for SmolStr {
     }
 }
 
+impl PartialEq<SmolStr> for SmolStr {
+    fn eq(&self, other: &SmolStr) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SmolStr {}
+
+impl hash::Hash for SmolStr {
+    fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher)
+    }
+}
+
+impl Borrow<str> for SmolStr {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
 impl PartialEq<str> for SmolStr {
     fn eq(&self, other: &str) -> bool {
         self.as_str() == other